@@ -5,6 +5,7 @@ use self::setup::{
     BackendBenchmark,
     BenchBucket,
     BenchBuffer,
+    BenchIndexedBuffer,
     BenchString,
     BENCH_LEN_STRINGS,
     BENCH_STRING_LEN,
@@ -82,6 +83,7 @@ fn bench_get_or_intern_static(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_get_or_intern_fill_with_capacity(c: &mut Criterion) {
@@ -108,6 +110,7 @@ fn bench_get_or_intern_fill_with_capacity(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_get_or_intern_fill(c: &mut Criterion) {
@@ -134,6 +137,7 @@ fn bench_get_or_intern_fill(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_get_or_intern_already_filled(c: &mut Criterion) {
@@ -160,6 +164,7 @@ fn bench_get_or_intern_already_filled(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_resolve_already_filled(c: &mut Criterion) {
@@ -186,6 +191,7 @@ fn bench_resolve_already_filled(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_resolve_unchecked_already_filled(c: &mut Criterion) {
@@ -215,6 +221,7 @@ fn bench_resolve_unchecked_already_filled(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_get_already_filled(c: &mut Criterion) {
@@ -241,6 +248,7 @@ fn bench_get_already_filled(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
 
 fn bench_iter_already_filled(c: &mut Criterion) {
@@ -275,4 +283,5 @@ fn bench_iter_already_filled(c: &mut Criterion) {
     bench_for_backend::<BenchBucket>(&mut g);
     bench_for_backend::<BenchString>(&mut g);
     bench_for_backend::<BenchBuffer>(&mut g);
+    bench_for_backend::<BenchIndexedBuffer>(&mut g);
 }
@@ -1,5 +1,5 @@
 use string_interner::{
-    backend::{Backend, BucketBackend, BufferBackend, StringBackend},
+    backend::{Backend, BucketBackend, BufferBackend, IndexedBufferBackend, StringBackend},
     DefaultSymbol,
     StringInterner,
 };
@@ -129,3 +129,9 @@ impl BackendBenchmark for BenchBuffer {
     const NAME: &'static str = "BufferBackend";
     type Backend = BufferBackend<DefaultSymbol>;
 }
+
+pub struct BenchIndexedBuffer;
+impl BackendBenchmark for BenchIndexedBuffer {
+    const NAME: &'static str = "IndexedBufferBackend";
+    type Backend = IndexedBufferBackend<DefaultSymbol>;
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "backends")]
+
+use crate::{
+    backend::BucketBackend,
+    DefaultHashBuilder,
+    DefaultSymbol,
+    Error,
+    StringInterner,
+    Symbol,
+};
+use core::{
+    cell::RefCell,
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+};
+
+/// A string interner that hands out string references which live as long as the interner.
+///
+/// Unlike [`StringInterner`] all methods take `&self`, so that resolved strings
+/// can be held while new strings are interned.
+///
+/// # Note
+///
+/// The interner is backed by the [`BucketBackend`] whose interned strings are
+/// never moved or deallocated while the backend is alive.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::ArenaInterner;
+/// let interner = <ArenaInterner>::new();
+/// let tiger: &str = interner.intern("Tiger");
+/// let horse = interner.get_or_intern("Horse");
+/// assert_eq!(interner.intern("Tiger"), tiger);
+/// assert_eq!(interner.resolve(horse), Some("Horse"));
+/// assert_eq!(tiger, "Tiger");
+/// ```
+pub struct ArenaInterner<S = DefaultSymbol, H = DefaultHashBuilder>
+where
+    S: Symbol,
+{
+    interner: RefCell<StringInterner<BucketBackend<S>, H>>,
+}
+
+impl<S, H> Debug for ArenaInterner<S, H>
+where
+    S: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArenaInterner")
+            .field("interner", &self.interner)
+            .finish()
+    }
+}
+
+impl<S, H> Default for ArenaInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> From<StringInterner<BucketBackend<S>, H>> for ArenaInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    #[inline]
+    fn from(interner: StringInterner<BucketBackend<S>, H>) -> Self {
+        Self {
+            interner: RefCell::new(interner),
+        }
+    }
+}
+
+impl<S, H> ArenaInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `ArenaInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::from(StringInterner::new())
+    }
+
+    /// Creates a new `ArenaInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::from(StringInterner::with_capacity(cap))
+    }
+}
+
+impl<S, H> ArenaInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ArenaInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self::from(StringInterner::with_hasher(hash_builder))
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.interner.borrow().len()
+    }
+
+    /// Returns `true` if the interner has no interned strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given string if any.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.borrow().get(string)
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&self, string: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        self.interner.borrow_mut().get_or_intern(string)
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern<T>(&self, string: T) -> Result<S, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.borrow_mut().try_get_or_intern(string)
+    }
+
+    /// Interns the given `'static` string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_static(&self, string: &'static str) -> S {
+        self.interner.borrow_mut().get_or_intern_static(string)
+    }
+
+    /// Interns the given string and returns the interned string.
+    ///
+    /// The returned string lives as long as the interner.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn intern<T>(&self, string: T) -> &str
+    where
+        T: AsRef<str>,
+    {
+        let symbol = self.get_or_intern(string);
+        self.resolve(symbol)
+            .expect("encountered invalid symbol for an interned string")
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    ///
+    /// The returned string lives as long as the interner.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let interner = self.interner.borrow();
+        let string: *const str = interner.resolve(symbol)?;
+        // SAFETY: The bucket backend never moves or deallocates its interned
+        //         strings while it is alive and the arena interner never
+        //         removes strings from it. Therefore the string lives as long
+        //         as `self` even after the borrow of the interner has ended.
+        Some(unsafe { &*string })
+    }
+
+    /// Returns the underlying string interner.
+    #[inline]
+    pub fn into_inner(self) -> StringInterner<BucketBackend<S>, H> {
+        self.interner.into_inner()
+    }
+}
@@ -15,6 +15,20 @@ impl FixedString {
         }
     }
 
+    /// Creates a new fixed string from the given string keeping its capacity.
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn from_string(contents: String) -> Self {
+        Self { contents }
+    }
+
+    /// Returns the contents of the fixed string.
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.contents
+    }
+
     /// Returns the underlying [`Box<str>`].
     ///
     /// Guarantees not to perform any reallocations in this process.
@@ -37,6 +51,31 @@ impl FixedString {
         self.contents.len()
     }
 
+    /// Removes all contents of the fixed string while keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.contents.clear();
+    }
+
+    /// Shortens the fixed string to the given length in bytes while keeping its capacity.
+    ///
+    /// # Panics
+    ///
+    /// If `len` does not lie on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.contents.truncate(len);
+    }
+
+    /// Returns the byte offset of the given string if it is stored in the fixed string.
+    #[inline]
+    pub fn offset_of(&self, string: &str) -> Option<usize> {
+        let start = self.contents.as_ptr() as usize;
+        (string.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset < self.len())
+    }
+
     /// Pushes the given string into the fixed string if there is enough capacity.
     ///
     /// Returns a reference to the pushed string if there was enough capacity to
@@ -4,11 +4,14 @@ mod fixed_str;
 mod interned_str;
 
 use self::{fixed_str::FixedString, interned_str::InternedStr};
-use super::Backend;
+use super::{Backend, TruncatableBackend};
 use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
 use alloc::{string::String, vec::Vec};
 use core::{iter::Enumerate, marker::PhantomData, slice};
 
+/// According to google the approx. word length is 5.
+const DEFAULT_WORD_LEN: usize = 5;
+
 /// An interner backend that reduces memory allocations by using string buckets.
 ///
 /// # Note
@@ -85,12 +88,13 @@ where
         = Iter<'a, S>
     where
         Self: 'a;
+    const KIND: &'static str = "bucket";
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn with_capacity(cap: usize) -> Self {
         Self {
             spans: Vec::with_capacity(cap),
-            head: FixedString::with_capacity(cap),
+            head: FixedString::with_capacity(cap * DEFAULT_WORD_LEN),
             full: Vec::new(),
             marker: Default::default(),
         }
@@ -98,17 +102,31 @@ where
 
     #[inline]
     fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        let symbol = self.next_symbol()?;
         // SAFETY: This is safe because we never hand out the returned
         //         interned string instance to the outside and only operate
         //         on it within this backend.
         let interned = unsafe { self.alloc(string) };
-        self.push_span(interned)
+        self.spans.push(interned);
+        Some(symbol)
     }
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn intern_static(&mut self, string: &'static str) -> Self::Symbol {
-        let interned = InternedStr::new(string);
-        self.push_span(interned)
+        self.try_intern_static(string)
+            .expect("encountered invalid symbol")
+    }
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn try_intern_static(&mut self, string: &'static str) -> Option<Self::Symbol> {
+        let symbol = self.next_symbol()?;
+        self.spans.push(InternedStr::new(string));
+        Some(symbol)
     }
 
     fn shrink_to_fit(&mut self) {
@@ -118,6 +136,48 @@ where
         self.full.shrink_to_fit();
     }
 
+    fn pack(&mut self) {
+        // Copies all interned strings into a single head bucket that fits them exactly.
+        let len_bytes = self.spans.iter().map(|span| span.as_str().len()).sum();
+        let mut head = FixedString::with_capacity(len_bytes);
+        let spans = self
+            .spans
+            .iter()
+            .map(|span| {
+                head.push_str(span.as_str())
+                    .expect("encountered invalid head capacity")
+            })
+            .collect();
+        // The spans must be replaced first since they refer to the buckets.
+        self.spans = spans;
+        self.head = head;
+        self.full = Vec::new();
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        let remaining = (self.head.capacity() - self.head.len()) / DEFAULT_WORD_LEN;
+        usize::min(self.spans.capacity(), self.spans.len() + remaining)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.spans.reserve(additional);
+        let additional_bytes = additional * DEFAULT_WORD_LEN;
+        if self.head.capacity() - self.head.len() < additional_bytes {
+            let new_cap =
+                (usize::max(self.head.capacity(), additional_bytes) + 1).next_power_of_two();
+            self.replace_head(new_cap);
+        }
+    }
+
+    fn clear(&mut self) {
+        // The spans must be cleared first since they refer to the buckets.
+        self.spans.clear();
+        self.full.clear();
+        // The head always is the biggest bucket so we keep it for reuse.
+        self.head.clear();
+    }
+
     #[inline]
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
         self.spans.get(symbol.to_usize()).map(InternedStr::as_str)
@@ -136,20 +196,49 @@ where
     }
 }
 
+impl<S> TruncatableBackend for BucketBackend<S>
+where
+    S: Symbol,
+{
+    /// Removes all but the first `len` interned strings from the backend.
+    ///
+    /// # Note
+    ///
+    /// Only the memory of removed strings that are stored in the current
+    /// head bucket is reclaimed for subsequently interned strings.
+    fn truncate(&mut self, len: usize) {
+        if len >= self.spans.len() {
+            return;
+        }
+        // Strings are pushed to the head bucket in the order they are interned,
+        // therefore all head bucket contents past the smallest offset of any
+        // removed string belong to removed strings as well.
+        let reclaimed = self.spans[len..]
+            .iter()
+            .filter_map(|span| self.head.offset_of(span.as_str()))
+            .min();
+        // The spans must be truncated first since they refer to the buckets.
+        self.spans.truncate(len);
+        if let Some(offset) = reclaimed {
+            self.head.truncate(offset);
+        }
+    }
+}
+
 impl<S> BucketBackend<S>
 where
     S: Symbol,
 {
-    /// Returns the next available symbol.
-    fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.spans.len())
+    /// Returns the next available symbol if any.
+    fn next_symbol(&self) -> Option<S> {
+        S::try_from_usize(self.spans.len())
     }
 
-    /// Pushes the given interned string into the spans and returns its symbol.
-    fn push_span(&mut self, interned: InternedStr) -> S {
-        let symbol = self.next_symbol();
-        self.spans.push(interned);
-        symbol
+    /// Replaces the head bucket with a new bucket of the given capacity.
+    fn replace_head(&mut self, new_cap: usize) {
+        let new_head = FixedString::with_capacity(new_cap);
+        let old_head = core::mem::replace(&mut self.head, new_head);
+        self.full.push(old_head.finish());
     }
 
     /// Interns a new string into the backend and returns a reference to it.
@@ -157,9 +246,7 @@ where
         let cap = self.head.capacity();
         if cap < self.head.len() + string.len() {
             let new_cap = (usize::max(cap, string.len()) + 1).next_power_of_two();
-            let new_head = FixedString::with_capacity(new_cap);
-            let old_head = core::mem::replace(&mut self.head, new_head);
-            self.full.push(old_head.finish());
+            self.replace_head(new_cap);
         }
         self.head
             .push_str(string)
@@ -167,6 +254,50 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S> BucketBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the end offsets of the interned strings and a buffer holding their contents.
+    pub(crate) fn to_raw_parts(&self) -> (Vec<usize>, String) {
+        let len_bytes = self.spans.iter().map(|span| span.as_str().len()).sum();
+        let mut buffer = String::with_capacity(len_bytes);
+        let ends = self
+            .spans
+            .iter()
+            .map(|span| {
+                buffer.push_str(span.as_str());
+                buffer.len()
+            })
+            .collect();
+        (ends, buffer)
+    }
+
+    /// Creates a backend from the end offsets of its strings and a buffer holding their contents.
+    ///
+    /// The buffer becomes the head bucket of the backend.
+    ///
+    /// # Errors
+    ///
+    /// If the raw parts do not form a valid backend.
+    pub(crate) fn from_raw_parts(ends: Vec<usize>, buffer: String) -> Result<Self, &'static str> {
+        super::validate_ends::<S>(&ends, &buffer)?;
+        let head = FixedString::from_string(buffer);
+        let mut from = 0;
+        let spans = ends
+            .into_iter()
+            .map(|to| InternedStr::new(&head.as_str()[core::mem::replace(&mut from, to)..to]))
+            .collect();
+        Ok(Self {
+            spans,
+            head,
+            full: Vec::new(),
+            marker: Default::default(),
+        })
+    }
+}
+
 impl<S> Clone for BucketBackend<S> {
     fn clone(&self) -> Self {
         // For performance reasons we copy all cloned strings into a single cloned
@@ -248,4 +379,11 @@ where
             .next()
             .map(|(id, interned)| (expect_valid_symbol(id), interned.as_str()))
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(id, interned)| (expect_valid_symbol(id), interned.as_str()))
+    }
 }
@@ -1,10 +1,16 @@
 #![cfg(feature = "backends")]
 
-use super::Backend;
-use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use super::{Backend, TruncatableBackend};
+use crate::{DefaultSymbol, Symbol};
 use alloc::vec::Vec;
 use core::{marker::PhantomData, mem, str};
 
+/// The expected number of bytes per interned string.
+///
+/// According to google the approx. word length is 5 and we encode
+/// the `usize` string length into the buffer as well.
+pub(super) const BYTES_PER_STRING: usize = 5 + mem::size_of::<usize>();
+
 /// An interner backend that appends all interned string information in a single buffer.
 ///
 /// # Usage Hint
@@ -76,10 +82,10 @@ impl<S> BufferBackend<S>
 where
     S: Symbol,
 {
-    /// Returns the next available symbol.
+    /// Returns the next available symbol if any.
     #[inline]
-    fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.buffer.len())
+    fn next_symbol(&self) -> Option<S> {
+        S::try_from_usize(self.buffer.len())
     }
 
     /// Resolves the string for the given symbol if any.
@@ -107,6 +113,25 @@ where
     /// The caller of the function has to ensure that calling this method
     /// is safe to do.
     unsafe fn resolve_index_to_str_unchecked(&self, index: usize) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let str_bytes = unsafe { self.resolve_index_to_bytes_unchecked(index) };
+        // SAFETY: It is guaranteed by the backend that only valid strings
+        //         are stored in this portion of the buffer.
+        unsafe { str::from_utf8_unchecked(str_bytes) }
+    }
+
+    /// Resolves the bytes of the string for the given symbol.
+    ///
+    /// # Note
+    ///
+    /// It is undefined behavior if the index does not resemble a string.
+    ///
+    /// # Safety
+    ///
+    /// The caller of the function has to ensure that calling this method
+    /// is safe to do.
+    unsafe fn resolve_index_to_bytes_unchecked(&self, index: usize) -> &[u8] {
         // SAFETY: The function is marked unsafe so that the caller guarantees
         //         that required invariants are checked.
         let bytes = unsafe { self.buffer.get_unchecked(index..) };
@@ -114,13 +139,9 @@ where
         //         that required invariants are checked.
         let (str_len, str_len_bytes) = unsafe { decode_var_usize_unchecked(bytes) };
         let index_str = index + str_len_bytes;
-        let str_bytes =
-            // SAFETY: The function is marked unsafe so that the caller guarantees
-            //         that required invariants are checked.
-            unsafe { self.buffer.get_unchecked(index_str..index_str + str_len) };
-        // SAFETY: It is guaranteed by the backend that only valid strings
-        //         are stored in this portion of the buffer.
-        unsafe { str::from_utf8_unchecked(str_bytes) }
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.buffer.get_unchecked(index_str..index_str + str_len) }
     }
 
     /// Pushes the given value onto the buffer with `var7` encoding.
@@ -131,22 +152,136 @@ where
         encode_var_usize(&mut self.buffer, value)
     }
 
-    /// Pushes the given string into the buffer and returns its span.
+    /// Pushes the given string into the buffer and returns its symbol.
     ///
-    /// # Panics
+    /// Returns `None` and leaves the backend unchanged if it ran out of symbols.
+    fn push_string(&mut self, string: &str) -> Option<S> {
+        self.push_bytes(string.as_bytes())
+    }
+
+    /// Pushes the given bytes into the buffer and returns their symbol.
     ///
-    /// If the backend ran out of symbols.
-    fn push_string(&mut self, string: &str) -> S {
-        let symbol = self.next_symbol();
-        let str_len = string.len();
-        let str_bytes = string.as_bytes();
-        self.encode_var_usize(str_len);
-        self.buffer.extend(str_bytes);
+    /// Returns `None` and leaves the backend unchanged if it ran out of symbols.
+    fn push_bytes(&mut self, bytes: &[u8]) -> Option<S> {
+        let symbol = self.next_symbol()?;
+        self.encode_var_usize(bytes.len());
+        self.buffer.extend(bytes);
         self.len_strings += 1;
-        symbol
+        Some(symbol)
+    }
+}
+
+/// Byte string support used by the [`BytesInterner`](`crate::BytesInterner`).
+///
+/// # Note
+///
+/// A backend that has been filled via `BufferBackend::try_intern_bytes` may hold
+/// strings that are not UTF-8 encoded and therefore must not be used as [`Backend`].
+impl<S> BufferBackend<S>
+where
+    S: Symbol,
+{
+    /// Tries to intern the given bytes and returns their symbol.
+    ///
+    /// Returns `None` if the backend ran out of symbols in which case
+    /// the backend remains unchanged.
+    #[inline]
+    pub(crate) fn try_intern_bytes(&mut self, bytes: &[u8]) -> Option<S> {
+        self.push_bytes(bytes)
+    }
+
+    /// Resolves the given symbol to its bytes.
+    #[inline]
+    pub(crate) fn resolve_bytes(&self, symbol: S) -> Option<&[u8]> {
+        self.resolve_index_to_str(symbol.to_usize())
+            .map(|(bytes, _)| bytes)
+    }
+
+    /// Resolves the given symbol to its bytes without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to provide a symbol that has been returned by the backend.
+    #[inline]
+    pub(crate) unsafe fn resolve_bytes_unchecked(&self, symbol: S) -> &[u8] {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.resolve_index_to_bytes_unchecked(symbol.to_usize()) }
+    }
+
+    /// Creates an iterator that yields all interned bytes and their symbols.
+    #[inline]
+    pub(crate) fn iter_bytes(&self) -> BytesIter<'_, S> {
+        BytesIter::new(self)
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S> BufferBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the number of interned strings and the buffer holding them.
+    pub(crate) fn as_raw_parts(&self) -> (usize, &[u8]) {
+        (self.len_strings, &self.buffer)
+    }
+
+    /// Creates a backend from the number of its strings and the buffer holding them.
+    ///
+    /// # Errors
+    ///
+    /// If the raw parts do not form a valid backend.
+    pub(crate) fn from_raw_parts(
+        len_strings: usize,
+        buffer: Vec<u8>,
+    ) -> Result<Self, &'static str> {
+        let mut len = 0;
+        let mut last = None;
+        validate_buffer(&buffer, |offset, _| {
+            len += 1;
+            last = Some(offset);
+        })?;
+        if len != len_strings {
+            return Err("invalid number of strings");
+        }
+        if last.is_some_and(|offset| S::try_from_usize(offset).is_none()) {
+            return Err("too many strings for the symbol type");
+        }
+        Ok(Self {
+            len_strings,
+            buffer,
+            marker: Default::default(),
+        })
+    }
+}
+
+/// Validates that `buffer` consists of `var7` length prefixed UTF-8 encoded strings.
+///
+/// Calls `f` with the offset of every string within the buffer and the string.
+///
+/// # Errors
+///
+/// If the buffer is malformed.
+pub(crate) fn validate_buffer<'a, F>(buffer: &'a [u8], mut f: F) -> Result<(), &'static str>
+where
+    F: FnMut(usize, &'a str),
+{
+    let mut offset = 0;
+    while offset < buffer.len() {
+        let (str_len, str_len_bytes) =
+            decode_var_usize(&buffer[offset..]).ok_or("invalid string length encoding")?;
+        let from = offset + str_len_bytes;
+        let str_bytes = from
+            .checked_add(str_len)
+            .and_then(|to| buffer.get(from..to))
+            .ok_or("string length exceeds the buffer")?;
+        let string = str::from_utf8(str_bytes).map_err(|_| "invalid UTF-8 encoded string")?;
+        f(offset, string);
+        offset = from + str_len;
+    }
+    Ok(())
+}
+
 impl<S> Backend for BufferBackend<S>
 where
     S: Symbol,
@@ -156,23 +291,24 @@ where
         = Iter<'a, S>
     where
         Self: 'a;
+    const KIND: &'static str = "buffer";
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn with_capacity(capacity: usize) -> Self {
-        /// We encode the `usize` string length into the buffer as well.
-        const LEN_USIZE: usize = mem::size_of::<usize>();
-        /// According to google the approx. word length is 5.
-        const DEFAULT_STR_LEN: usize = 5;
-        let bytes_per_string = DEFAULT_STR_LEN + LEN_USIZE;
         Self {
             len_strings: 0,
-            buffer: Vec::with_capacity(capacity * bytes_per_string),
+            buffer: Vec::with_capacity(capacity * BYTES_PER_STRING),
             marker: Default::default(),
         }
     }
 
     #[inline]
     fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
         self.push_string(string)
     }
 
@@ -188,6 +324,21 @@ where
         self.buffer.shrink_to_fit();
     }
 
+    #[inline]
+    fn capacity(&self) -> usize {
+        let remaining = (self.buffer.capacity() - self.buffer.len()) / BYTES_PER_STRING;
+        self.len_strings + remaining
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional * BYTES_PER_STRING);
+    }
+
+    fn clear(&mut self) {
+        self.len_strings = 0;
+        self.buffer.clear();
+    }
+
     #[inline]
     unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
         // SAFETY: The function is marked unsafe so that the caller guarantees
@@ -201,11 +352,37 @@ where
     }
 }
 
+impl<S> TruncatableBackend for BufferBackend<S>
+where
+    S: Symbol,
+{
+    /// Removes all but the first `len` interned strings from the backend.
+    ///
+    /// # Note
+    ///
+    /// This is linear in the number of retained strings since the
+    /// backend has to walk its buffer to find the truncation point.
+    fn truncate(&mut self, len: usize) {
+        if len >= self.len_strings {
+            return;
+        }
+        let mut index = 0;
+        for _ in 0..len {
+            let (_, next) = self
+                .resolve_index_to_str(index)
+                .expect("encountered invalid string in buffer");
+            index = next;
+        }
+        self.buffer.truncate(index);
+        self.len_strings = len;
+    }
+}
+
 /// Encodes the value using variable length encoding into the buffer.
 ///
 /// Returns the amount of bytes used for the encoding.
 #[inline]
-fn encode_var_usize(buffer: &mut Vec<u8>, mut value: usize) -> usize {
+pub(crate) fn encode_var_usize(buffer: &mut Vec<u8>, mut value: usize) -> usize {
     if value <= 0x7F {
         // Shortcut the common case for low value.
         buffer.push(value as u8);
@@ -235,7 +412,7 @@ fn encode_var_usize(buffer: &mut Vec<u8>, mut value: usize) -> usize {
 /// The caller has to make sure that the buffer contains the necessary
 /// bytes needed to properly decode a valid `usize` value.
 #[inline]
-unsafe fn decode_var_usize_unchecked(buffer: &[u8]) -> (usize, usize) {
+pub(crate) unsafe fn decode_var_usize_unchecked(buffer: &[u8]) -> (usize, usize) {
     let first = unsafe { *buffer.get_unchecked(0) };
     match first {
         byte if byte <= 0x7F_u8 => (byte as usize, 1),
@@ -276,7 +453,7 @@ unsafe fn decode_var_usize_unchecked_cold(buffer: &[u8]) -> (usize, usize) {
 /// Returns the decoded value as first return value.
 /// Returns the number of decoded bytes as second return value.
 #[inline]
-fn decode_var_usize(buffer: &[u8]) -> Option<(usize, usize)> {
+pub(crate) fn decode_var_usize(buffer: &[u8]) -> Option<(usize, usize)> {
     match buffer.first() {
         None => None,
         Some(&byte) if byte <= 0x7F_u8 => Some((byte as usize, 1)),
@@ -321,14 +498,60 @@ where
 }
 
 pub struct Iter<'a, S> {
+    bytes: BytesIter<'a, S>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a BufferBackend<S>) -> Self {
+        Self {
+            bytes: BytesIter::new(backend),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bytes.next().map(|(symbol, bytes)| {
+            // SAFETY: It is guaranteed by the backend that only valid strings
+            //         are stored in its buffer when it is used as `Backend`.
+            let string = unsafe { str::from_utf8_unchecked(bytes) };
+            (symbol, string)
+        })
+    }
+}
+
+impl<S> ExactSizeIterator for Iter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+/// Iterator over the interned bytes of a [`BufferBackend`] and their symbols.
+pub struct BytesIter<'a, S> {
     backend: &'a BufferBackend<S>,
     remaining: usize,
     next: usize,
 }
 
-impl<'a, S> Iter<'a, S> {
+impl<'a, S> BytesIter<'a, S> {
     #[cfg_attr(feature = "inline-more", inline)]
-    pub fn new(backend: &'a BufferBackend<S>) -> Self {
+    fn new(backend: &'a BufferBackend<S>) -> Self {
         Self {
             backend,
             remaining: backend.len_strings,
@@ -337,11 +560,11 @@ impl<'a, S> Iter<'a, S> {
     }
 }
 
-impl<'a, S> Iterator for Iter<'a, S>
+impl<'a, S> Iterator for BytesIter<'a, S>
 where
     S: Symbol,
 {
-    type Item = (S, &'a str);
+    type Item = (S, &'a [u8]);
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -354,18 +577,15 @@ where
         self.backend
             .resolve_index_to_str(self.next)
             .and_then(|(bytes, next)| {
-                // SAFETY: Within the iterator all indices given to `resolv_index_to_str`
-                //         are properly pointing to the start of each interned string.
-                let string = unsafe { str::from_utf8_unchecked(bytes) };
                 let symbol = S::try_from_usize(self.next)?;
                 self.next = next;
                 self.remaining -= 1;
-                Some((symbol, string))
+                Some((symbol, bytes))
             })
     }
 }
 
-impl<S> ExactSizeIterator for Iter<'_, S>
+impl<S> ExactSizeIterator for BytesIter<'_, S>
 where
     S: Symbol,
 {
@@ -0,0 +1,318 @@
+#![cfg(feature = "backends")]
+
+use super::{Backend, TruncatableBackend};
+use crate::{
+    normalize::Normalizer,
+    symbol::expect_valid_symbol,
+    DefaultSymbol,
+    StringInterner,
+    Symbol,
+};
+use alloc::{string::String, vec::Vec};
+use core::{ffi::CStr, hash::BuildHasher, iter::Enumerate, marker::PhantomData, slice};
+
+/// According to google the approx. word length is 5 plus the trailing NUL byte.
+const DEFAULT_WORD_LEN: usize = 6;
+
+/// An interner backend that stores every interned string with a trailing NUL byte.
+///
+/// The interned strings are stored like in the [`StringBackend`](`super::StringBackend`)
+/// but are NUL terminated so that they can be resolved to [`CStr`] via
+/// [`CStrBackend::resolve_cstr`] and handed to FFI without copying them.
+///
+/// # Note
+///
+/// Strings with interior NUL bytes can be interned but cannot be resolved to [`CStr`].
+///
+/// # Usage Hint
+///
+/// Use this backend if interned strings are passed to C libraries.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **ok**   |
+/// | Allocations | **good** |
+/// | Footprint   | **good** |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::{backend::CStrBackend, StringInterner};
+/// let mut interner = <StringInterner<CStrBackend>>::new();
+/// let name = interner.get_or_intern("main");
+/// assert_eq!(interner.resolve(name), Some("main"));
+/// assert_eq!(interner.resolve_cstr(name), Some(c"main"));
+/// ```
+#[derive(Debug)]
+pub struct CStrBackend<S = DefaultSymbol> {
+    /// The end offsets of the interned strings including their trailing NUL byte.
+    ends: Vec<usize>,
+    buffer: String,
+    /// The indices of the interned strings with interior NUL bytes in ascending order.
+    interior_nuls: Vec<usize>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> PartialEq for CStrBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ends == other.ends
+            && self.buffer == other.buffer
+            && self.interior_nuls == other.interior_nuls
+    }
+}
+
+impl<S> Eq for CStrBackend<S> where S: Symbol {}
+
+impl<S> Clone for CStrBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            ends: self.ends.clone(),
+            buffer: self.buffer.clone(),
+            interior_nuls: self.interior_nuls.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for CStrBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            ends: Vec::default(),
+            buffer: String::default(),
+            interior_nuls: Vec::default(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> CStrBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the next available symbol if any.
+    fn next_symbol(&self) -> Option<S> {
+        S::try_from_usize(self.ends.len())
+    }
+
+    /// Returns the NUL terminated string at the given index if any.
+    fn nul_terminated(&self, index: usize) -> Option<&str> {
+        let to = *self.ends.get(index)?;
+        let from = self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0);
+        Some(&self.buffer[from..to])
+    }
+
+    /// Resolves the given symbol to its original string as [`CStr`].
+    ///
+    /// Returns `None` if the symbol is invalid or if the string contains
+    /// interior NUL bytes.
+    #[inline]
+    pub fn resolve_cstr(&self, symbol: S) -> Option<&CStr> {
+        let index = symbol.to_usize();
+        let string = self.nul_terminated(index)?;
+        if self.interior_nuls.binary_search(&index).is_ok() {
+            return None;
+        }
+        // SAFETY: The string has a trailing NUL byte and no interior NUL bytes
+        //         since those have been recorded when interning the string.
+        Some(unsafe { CStr::from_bytes_with_nul_unchecked(string.as_bytes()) })
+    }
+}
+
+impl<S> Backend for CStrBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, S>
+    where
+        Self: 'a;
+    const KIND: &'static str = "cstr";
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            ends: Vec::with_capacity(cap),
+            buffer: String::with_capacity(cap * DEFAULT_WORD_LEN),
+            interior_nuls: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        let symbol = self.next_symbol()?;
+        if string.contains('\0') {
+            self.interior_nuls.push(self.ends.len());
+        }
+        self.buffer.push_str(string);
+        self.buffer.push('\0');
+        self.ends.push(self.buffer.len());
+        Some(symbol)
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.nul_terminated(symbol.to_usize())
+            .map(|string| &string[..string.len() - 1])
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.ends.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+        self.interior_nuls.shrink_to_fit();
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        let remaining = (self.buffer.capacity() - self.buffer.len()) / DEFAULT_WORD_LEN;
+        usize::min(self.ends.capacity(), self.ends.len() + remaining)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.ends.reserve(additional);
+        self.buffer.reserve(additional * DEFAULT_WORD_LEN);
+    }
+
+    fn clear(&mut self) {
+        self.ends.clear();
+        self.buffer.clear();
+        self.interior_nuls.clear();
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        let index = symbol.to_usize();
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let to = unsafe { *self.ends.get_unchecked(index) };
+        let from = self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0);
+        // SAFETY: The range excludes the trailing NUL byte of the string
+        //         and therefore lies on `char` boundaries of the buffer.
+        unsafe { self.buffer.get_unchecked(from..to - 1) }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<S> TruncatableBackend for CStrBackend<S>
+where
+    S: Symbol,
+{
+    fn truncate(&mut self, len: usize) {
+        if len >= self.ends.len() {
+            return;
+        }
+        let to = len.checked_sub(1).map(|last| self.ends[last]).unwrap_or(0);
+        self.buffer.truncate(to);
+        self.ends.truncate(len);
+        let interior_nuls = self.interior_nuls.partition_point(|&index| index < len);
+        self.interior_nuls.truncate(interior_nuls);
+    }
+}
+
+impl<S, H, N> StringInterner<CStrBackend<S>, H, N>
+where
+    S: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Returns the string for the given `symbol` as [`CStr`] if any.
+    ///
+    /// Returns `None` if the symbol is invalid or if the string contains
+    /// interior NUL bytes.
+    #[inline]
+    pub fn resolve_cstr(&self, symbol: S) -> Option<&CStr> {
+        self.backend().resolve_cstr(symbol)
+    }
+}
+
+impl<'a, S> IntoIterator for &'a CStrBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    buffer: &'a str,
+    start: usize,
+    ends: Enumerate<slice::Iter<'a, usize>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a CStrBackend<S>) -> Self {
+        Self {
+            buffer: &backend.buffer,
+            start: 0,
+            ends: backend.ends.iter().enumerate(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ends.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ends.next().map(|(id, &to)| {
+            let from = core::mem::replace(&mut self.start, to);
+            (expect_valid_symbol(id), &self.buffer[from..to - 1])
+        })
+    }
+}
+
+impl<S> ExactSizeIterator for Iter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+}
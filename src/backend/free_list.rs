@@ -0,0 +1,277 @@
+#![cfg(feature = "backends")]
+
+use super::{Backend, RemovableBackend};
+use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use alloc::{boxed::Box, vec::Vec};
+use core::{iter::Enumerate, marker::PhantomData, slice};
+
+/// An interner backend that supports removal of interned strings.
+///
+/// The symbols of removed strings are put into a free list and handed out
+/// again for subsequently interned strings. Therefore the memory consumed by
+/// the backend is bounded by the maximum number of strings that are
+/// interned at the same time.
+///
+/// # Usage Hint
+///
+/// Use this backend if interned strings are frequently removed again,
+/// for example in long running servers that intern user supplied keys.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **ok**   |
+/// | Resolve     | **good** |
+/// | Allocations | **bad**  |
+/// | Footprint   | **ok**   |
+/// | Supports `get_or_intern_static` | **no** |
+/// | Supports `remove` | **yes** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **no**   |
+/// | Iteration   | **ok**   |
+#[derive(Debug)]
+pub struct FreeListBackend<S = DefaultSymbol> {
+    slots: Vec<Slot>,
+    /// The index of the most recently vacated slot if any.
+    free: Option<usize>,
+    len: usize,
+    marker: PhantomData<fn() -> S>,
+}
+
+/// A slot of the [`FreeListBackend`] that may or may not hold a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Slot {
+    /// The slot holds an interned string.
+    Occupied(Box<str>),
+    /// The slot is part of the free list.
+    Vacant {
+        /// The index of the next vacant slot of the free list if any.
+        next_free: Option<usize>,
+    },
+}
+
+impl Slot {
+    /// Returns the string of the slot if it is occupied.
+    #[inline]
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Occupied(string) => Some(string),
+            Self::Vacant { .. } => None,
+        }
+    }
+}
+
+impl<S> PartialEq for FreeListBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<S> Eq for FreeListBackend<S> where S: Symbol {}
+
+impl<S> Clone for FreeListBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            free: self.free,
+            len: self.len,
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for FreeListBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: None,
+            len: 0,
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Backend for FreeListBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, S>
+    where
+        Self: 'a;
+    const KIND: &'static str = "free-list";
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(cap),
+            free: None,
+            len: 0,
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        let index = self.free.unwrap_or(self.slots.len());
+        let symbol = S::try_from_usize(index)?;
+        let occupied = Slot::Occupied(string.into());
+        match self.free {
+            Some(index) => {
+                let vacant = core::mem::replace(&mut self.slots[index], occupied);
+                let Slot::Vacant { next_free } = vacant else {
+                    panic!("encountered occupied slot in the free list")
+                };
+                self.free = next_free;
+            }
+            None => self.slots.push(occupied),
+        }
+        self.len += 1;
+        Some(symbol)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.slots.reserve(additional);
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.free = None;
+        self.len = 0;
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.slots.get(symbol.to_usize()).and_then(Slot::as_str)
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        match unsafe { self.slots.get_unchecked(symbol.to_usize()) } {
+            Slot::Occupied(string) => string,
+            // SAFETY: The function is marked unsafe so that the caller guarantees
+            //         that the symbol refers to a string that has not been removed.
+            Slot::Vacant { .. } => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+
+    #[inline]
+    fn try_remove(&mut self, symbol: Self::Symbol) -> bool {
+        RemovableBackend::remove(self, symbol)
+    }
+}
+
+impl<S> RemovableBackend for FreeListBackend<S>
+where
+    S: Symbol,
+{
+    fn remove(&mut self, symbol: Self::Symbol) -> bool {
+        let index = symbol.to_usize();
+        let Some(slot @ Slot::Occupied(_)) = self.slots.get_mut(index) else {
+            return false;
+        };
+        *slot = Slot::Vacant {
+            next_free: self.free,
+        };
+        self.free = Some(index);
+        self.len -= 1;
+        true
+    }
+}
+
+impl<'a, S> IntoIterator for &'a FreeListBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    iter: Enumerate<slice::Iter<'a, Slot>>,
+    remaining: usize,
+    symbol_marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a FreeListBackend<S>) -> Self {
+        Self {
+            iter: backend.slots.iter().enumerate(),
+            remaining: backend.len,
+            symbol_marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, string) = self
+            .iter
+            .find_map(|(id, slot)| slot.as_str().map(|string| (id, string)))?;
+        self.remaining -= 1;
+        Some((expect_valid_symbol(id), string))
+    }
+}
+
+impl<S> ExactSizeIterator for Iter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
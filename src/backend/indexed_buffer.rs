@@ -0,0 +1,397 @@
+#![cfg(feature = "backends")]
+
+use super::{
+    buffer::{decode_var_usize, decode_var_usize_unchecked, encode_var_usize, BYTES_PER_STRING},
+    Backend,
+    TruncatableBackend,
+};
+use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use alloc::vec::Vec;
+use core::{iter::Enumerate, marker::PhantomData, slice, str};
+
+/// The number of consecutive strings whose offsets are relative to the same block offset.
+const BLOCK_LEN: usize = 256;
+
+/// An interner backend that appends all interned strings to a single buffer
+/// and indexes them by their offsets.
+///
+/// # Note
+///
+/// Stores the interned strings in the same `var7` length prefixed layout as the
+/// [`BufferBackend`](`super::BufferBackend`) but additionally keeps the offsets of
+/// all strings within the buffer. This makes its symbols contiguous and resolution
+/// a direct look-up at the cost of some memory per interned string.
+///
+/// The offsets are stored as 32-bit values relative to the offset of their block
+/// of 256 consecutive strings. Therefore the backend uses roughly 4 bytes per
+/// interned string in addition to the [`BufferBackend`](`super::BufferBackend`)
+/// layout which is half of the end offsets of the [`StringBackend`](`super::StringBackend`).
+/// Interning fails with [`Error::OutOfSymbols`](`crate::Error::OutOfSymbols`) if the
+/// strings of a single block take up more than 4 GiB.
+///
+/// # Usage Hint
+///
+/// Use this backend if memory consumption matters but symbols are frequently resolved.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **good** |
+/// | Allocations | **good** |
+/// | Footprint   | **good** |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+#[derive(Debug)]
+pub struct IndexedBufferBackend<S = DefaultSymbol> {
+    /// The offsets of the first string of every block of `BLOCK_LEN` consecutive strings.
+    block_offsets: Vec<usize>,
+    /// The offsets of the interned strings relative to their block indexed by their symbols.
+    offsets: Vec<u32>,
+    buffer: Vec<u8>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> PartialEq for IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.offsets.len() == other.offsets.len() && self.buffer == other.buffer
+    }
+}
+
+impl<S> Eq for IndexedBufferBackend<S> where S: Symbol {}
+
+impl<S> Clone for IndexedBufferBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            block_offsets: self.block_offsets.clone(),
+            offsets: self.offsets.clone(),
+            buffer: self.buffer.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for IndexedBufferBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            block_offsets: Vec::new(),
+            offsets: Vec::new(),
+            buffer: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the next available symbol if any.
+    #[inline]
+    fn next_symbol(&self) -> Option<S> {
+        S::try_from_usize(self.offsets.len())
+    }
+
+    /// Returns the offset within the buffer of the string with the given `index` if any.
+    #[inline]
+    fn offset_of(&self, index: usize) -> Option<usize> {
+        let relative = *self.offsets.get(index)?;
+        Some(self.block_offsets[index / BLOCK_LEN] + relative as usize)
+    }
+
+    /// Returns the offset within the buffer of the string with the given `index`.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to make sure that `index` refers to an interned string.
+    #[inline]
+    unsafe fn offset_of_unchecked(&self, index: usize) -> usize {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe {
+            let relative = *self.offsets.get_unchecked(index);
+            *self.block_offsets.get_unchecked(index / BLOCK_LEN) + relative as usize
+        }
+    }
+
+    /// Pushes the given `offset` of the next string to the offset table.
+    ///
+    /// Returns `None` and leaves the offset table unchanged if the offset cannot
+    /// be represented relative to its block.
+    fn push_offset(&mut self, offset: usize) -> Option<()> {
+        if self.offsets.len().is_multiple_of(BLOCK_LEN) {
+            self.block_offsets.push(offset);
+        }
+        let block_offset = *self.block_offsets.last()?;
+        let relative = u32::try_from(offset - block_offset).ok()?;
+        self.offsets.push(relative);
+        Some(())
+    }
+
+    /// Returns the string that starts at the given `offset` of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// If `offset` does not point to the start of a string within the buffer.
+    fn offset_to_str(&self, offset: usize) -> &str {
+        let (str_len, str_len_bytes) =
+            decode_var_usize(&self.buffer[offset..]).expect("encountered invalid string offset");
+        let offset_str = offset + str_len_bytes;
+        let str_bytes = &self.buffer[offset_str..offset_str + str_len];
+        // SAFETY: It is guaranteed by the backend that only valid strings
+        //         are stored in this portion of the buffer.
+        unsafe { str::from_utf8_unchecked(str_bytes) }
+    }
+
+    /// Returns the string that starts at the given `offset` of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to make sure that `offset` points to the start of a string
+    /// within the buffer.
+    unsafe fn offset_to_str_unchecked(&self, offset: usize) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let bytes = unsafe { self.buffer.get_unchecked(offset..) };
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let (str_len, str_len_bytes) = unsafe { decode_var_usize_unchecked(bytes) };
+        let offset_str = offset + str_len_bytes;
+        let str_bytes =
+            // SAFETY: The function is marked unsafe so that the caller guarantees
+            //         that required invariants are checked.
+            unsafe { self.buffer.get_unchecked(offset_str..offset_str + str_len) };
+        // SAFETY: It is guaranteed by the backend that only valid strings
+        //         are stored in this portion of the buffer.
+        unsafe { str::from_utf8_unchecked(str_bytes) }
+    }
+
+    /// Pushes the given string into the buffer and returns its symbol.
+    ///
+    /// Returns `None` and leaves the backend unchanged if it ran out of symbols.
+    fn push_string(&mut self, string: &str) -> Option<S> {
+        let symbol = self.next_symbol()?;
+        self.push_offset(self.buffer.len())?;
+        encode_var_usize(&mut self.buffer, string.len());
+        self.buffer.extend_from_slice(string.as_bytes());
+        Some(symbol)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S> IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the buffer holding the interned strings.
+    pub(crate) fn as_raw_parts(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Creates a backend from the buffer holding its strings.
+    ///
+    /// The offsets of the strings are restored from the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer does not form a valid backend.
+    pub(crate) fn from_raw_parts(buffer: Vec<u8>) -> Result<Self, &'static str> {
+        let mut backend = Self::default();
+        let mut valid_offsets = true;
+        super::buffer::validate_buffer(&buffer, |offset, _| {
+            valid_offsets &= backend.push_offset(offset).is_some();
+        })?;
+        if !valid_offsets {
+            return Err("too many bytes for the offset table");
+        }
+        if let Some(last) = backend.offsets.len().checked_sub(1) {
+            if S::try_from_usize(last).is_none() {
+                return Err("too many strings for the symbol type");
+            }
+        }
+        backend.buffer = buffer;
+        Ok(backend)
+    }
+}
+
+impl<S> Backend for IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, S>
+    where
+        Self: 'a;
+    const KIND: &'static str = "indexed-buffer";
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            block_offsets: Vec::with_capacity(capacity.div_ceil(BLOCK_LEN)),
+            offsets: Vec::with_capacity(capacity),
+            buffer: Vec::with_capacity(capacity * BYTES_PER_STRING),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        self.push_string(string)
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.offset_of(symbol.to_usize())
+            .map(|offset| self.offset_to_str(offset))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.block_offsets.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        let remaining = (self.buffer.capacity() - self.buffer.len()) / BYTES_PER_STRING;
+        usize::min(self.offsets.capacity(), self.offsets.len() + remaining)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.block_offsets.reserve(additional.div_ceil(BLOCK_LEN));
+        self.offsets.reserve(additional);
+        self.buffer.reserve(additional * BYTES_PER_STRING);
+    }
+
+    fn clear(&mut self) {
+        self.block_offsets.clear();
+        self.offsets.clear();
+        self.buffer.clear();
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe {
+            let offset = self.offset_of_unchecked(symbol.to_usize());
+            self.offset_to_str_unchecked(offset)
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<S> TruncatableBackend for IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    fn truncate(&mut self, len: usize) {
+        if let Some(offset) = self.offset_of(len) {
+            self.buffer.truncate(offset);
+            self.offsets.truncate(len);
+            self.block_offsets.truncate(len.div_ceil(BLOCK_LEN));
+        }
+    }
+}
+
+impl<'a, S> IntoIterator for &'a IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    backend: &'a IndexedBufferBackend<S>,
+    offsets: Enumerate<slice::Iter<'a, u32>>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a IndexedBufferBackend<S>) -> Self {
+        Self {
+            backend,
+            offsets: backend.offsets.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, S> Iter<'a, S>
+where
+    S: Symbol,
+{
+    /// Returns the symbol and string of the given `id` and its `relative` offset.
+    #[inline]
+    fn item(&self, id: usize, relative: u32) -> (S, &'a str) {
+        let offset = self.backend.block_offsets[id / BLOCK_LEN] + relative as usize;
+        (expect_valid_symbol(id), self.backend.offset_to_str(offset))
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.offsets
+            .next()
+            .map(|(id, &relative)| self.item(id, relative))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.offsets
+            .nth(n)
+            .map(|(id, &relative)| self.item(id, relative))
+    }
+}
+
+impl<S> ExactSizeIterator for Iter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
@@ -0,0 +1,400 @@
+#![cfg(feature = "mmap")]
+
+use super::Backend;
+use crate::{
+    persist::{decode_entry, split_persisted},
+    symbol::expect_valid_symbol,
+    DefaultSymbol,
+    Error,
+    Symbol,
+};
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    ops::Range,
+    str,
+};
+use memmap2::Mmap;
+use std::{fs::File, io, path::Path};
+
+/// The number of consecutive entries whose offsets are relative to the same block offset.
+const BLOCK_LEN: usize = 256;
+
+/// A read-only interner backend that memory maps an interner persisted via
+/// [`StringInterner::write_to`](`crate::StringInterner::write_to`).
+///
+/// The persisted strings are resolved directly from the mapped file without
+/// copying them onto the heap. Only the offsets of the strings within the file
+/// are kept on the heap which takes roughly 4 bytes per string.
+///
+/// # Note
+///
+/// The backend resolves the symbols that have been persisted with the strings,
+/// regardless of the backend of the persisted interner.
+/// Use [`StringInterner::from_backend`](`crate::StringInterner::from_backend`)
+/// to build the hash table required by [`StringInterner::get`](`crate::StringInterner::get`).
+///
+/// The backend is read-only and therefore interning new strings always fails
+/// with [`Error::ReadOnly`](`Error::ReadOnly`).
+///
+/// # Usage Hint
+///
+/// Use this backend to resolve the symbols of very large persisted interners
+/// without loading their strings into memory.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **n/a**  |
+/// | Resolve     | **good** |
+/// | Allocations | **best** |
+/// | Footprint   | **best** |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **n/a**  |
+/// | Iteration   | **good** |
+///
+/// # Example
+///
+/// ```no_run
+/// # use string_interner::{backend::MmapBackend, StringInterner};
+/// // SAFETY: The file is not modified while it is mapped.
+/// let backend = unsafe { <MmapBackend>::open("interner.bin") }.unwrap();
+/// let interner = <StringInterner<MmapBackend>>::from_backend(backend).unwrap();
+/// if let Some(symbol) = interner.get("Tiger") {
+///     assert_eq!(interner.resolve(symbol), Some("Tiger"));
+/// }
+/// ```
+pub struct MmapBackend<S = DefaultSymbol> {
+    /// The mapped persisted interner if any.
+    map: Option<Mmap>,
+    /// The range of the body within the mapped persisted interner.
+    body: Range<usize>,
+    /// The offsets of the first entry of every block of `BLOCK_LEN` consecutive entries.
+    block_offsets: Vec<usize>,
+    /// The offsets of the entries relative to their block in the order of their symbols.
+    offsets: Vec<u32>,
+    /// `true` if the symbol of every entry is equal to its index.
+    contiguous: bool,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> Debug for MmapBackend<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapBackend")
+            .field("len", &self.offsets.len())
+            .field("contiguous", &self.contiguous)
+            .finish()
+    }
+}
+
+impl<S> PartialEq for MmapBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<S> Eq for MmapBackend<S> where S: Symbol {}
+
+impl<S> Default for MmapBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            map: None,
+            body: 0..0,
+            block_offsets: Vec::new(),
+            offsets: Vec::new(),
+            contiguous: true,
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> MmapBackend<S>
+where
+    S: Symbol,
+{
+    /// Memory maps the interner persisted in the file at the given `path`.
+    ///
+    /// The layout of the file is validated once while opening it.
+    ///
+    /// # Errors
+    ///
+    /// - If the file cannot be opened or mapped.
+    /// - If the file is malformed or its checksum does not match.
+    /// - If the persisted symbols are not ascending or out of bounds for `S`.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to make sure that the file is not modified or truncated
+    /// while the backend is alive. Otherwise resolved strings may change or
+    /// accessing them may cause undefined behavior.
+    pub unsafe fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that the file is not modified while it is mapped.
+        let map = unsafe { Mmap::map(&file)? };
+        Self::from_mmap(map)
+    }
+
+    /// Creates a backend from the mapped persisted interner.
+    fn from_mmap(map: Mmap) -> io::Result<Self> {
+        let (header, body) = split_persisted(&map)?;
+        let invalid_data = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+        let capacity = header.len_strings.min(body.len());
+        let mut backend = Self {
+            map: None,
+            body: 0..0,
+            block_offsets: Vec::with_capacity(capacity.div_ceil(BLOCK_LEN)),
+            offsets: Vec::with_capacity(capacity),
+            contiguous: true,
+            marker: Default::default(),
+        };
+        let mut last = None;
+        let mut offset = 0;
+        while offset < body.len() {
+            let (symbol, _, len) = decode_entry(&body[offset..])?;
+            if last.is_some_and(|last| symbol <= last) {
+                return Err(invalid_data("symbols are not ascending"));
+            }
+            if S::try_from_usize(symbol).is_none() {
+                return Err(invalid_data("symbol is out of bounds for the symbol type"));
+            }
+            backend.contiguous &= symbol == backend.offsets.len();
+            backend
+                .push_offset(offset)
+                .ok_or_else(|| invalid_data("too many bytes for the offset table"))?;
+            last = Some(symbol);
+            offset += len;
+        }
+        if backend.offsets.len() != header.len_strings {
+            return Err(invalid_data("mismatching number of strings"));
+        }
+        let body_start = map.len() - body.len() - size_of::<u64>();
+        backend.body = body_start..body_start + body.len();
+        backend.map = Some(map);
+        Ok(backend)
+    }
+
+    /// Pushes the given `offset` of the next entry to the offset table.
+    ///
+    /// Returns `None` and leaves the offset table unchanged if the offset cannot
+    /// be represented relative to its block.
+    fn push_offset(&mut self, offset: usize) -> Option<()> {
+        if self.offsets.len().is_multiple_of(BLOCK_LEN) {
+            self.block_offsets.push(offset);
+        }
+        let block_offset = *self.block_offsets.last()?;
+        let relative = u32::try_from(offset - block_offset).ok()?;
+        self.offsets.push(relative);
+        Some(())
+    }
+
+    /// Returns the offset within the body of the entry with the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[inline]
+    fn offset_of(&self, index: usize) -> usize {
+        self.block_offsets[index / BLOCK_LEN] + self.offsets[index] as usize
+    }
+
+    /// Returns the body of the mapped persisted interner.
+    #[inline]
+    fn body(&self) -> &[u8] {
+        match &self.map {
+            Some(map) => &map[self.body.clone()],
+            None => &[],
+        }
+    }
+
+    /// Returns the symbol and the string of the entry at `offset` within the body.
+    ///
+    /// # Panics
+    ///
+    /// If `offset` does not point to the start of an entry within the body.
+    fn entry(&self, offset: usize) -> (usize, &str) {
+        let bytes = &self.body()[offset..];
+        let (symbol, symbol_len) =
+            super::decode_var_usize(bytes).expect("encountered invalid entry offset");
+        let bytes = &bytes[symbol_len..];
+        let (str_len, str_len_bytes) =
+            super::decode_var_usize(bytes).expect("encountered invalid entry offset");
+        let str_bytes = &bytes[str_len_bytes..str_len_bytes + str_len];
+        // SAFETY: All strings of the body have been validated to be UTF-8
+        //         encoded when the backend was created.
+        (symbol, unsafe { str::from_utf8_unchecked(str_bytes) })
+    }
+
+    /// Returns the index of the entry with the given `symbol` if any.
+    fn index_of(&self, symbol: S) -> Option<usize> {
+        let symbol = symbol.to_usize();
+        if self.contiguous {
+            return (symbol < self.offsets.len()).then_some(symbol);
+        }
+        let (mut low, mut high) = (0, self.offsets.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.entry(self.offset_of(mid)).0.cmp(&symbol) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+}
+
+impl<S> Backend for MmapBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, S>
+    where
+        Self: 'a;
+    const KIND: &'static str = "mmap";
+    const READ_ONLY: bool = true;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn intern(&mut self, _string: &str) -> Self::Symbol {
+        panic!("{}", Error::ReadOnly)
+    }
+
+    #[inline]
+    fn try_intern(&mut self, _string: &str) -> Option<Self::Symbol> {
+        None
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.index_of(symbol)
+            .map(|index| self.entry(self.offset_of(index)).1)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.block_offsets.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.resolve(symbol).unwrap_unchecked() }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<'a, S> IntoIterator for &'a MmapBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    backend: &'a MmapBackend<S>,
+    indices: Range<usize>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a MmapBackend<S>) -> Self {
+        Self {
+            backend,
+            indices: 0..backend.offsets.len(),
+        }
+    }
+}
+
+impl<'a, S> Iter<'a, S>
+where
+    S: Symbol,
+{
+    /// Returns the symbol and the string of the entry with the given `index`.
+    #[inline]
+    fn item(&self, index: usize) -> (S, &'a str) {
+        let (symbol, string) = self.backend.entry(self.backend.offset_of(index));
+        (expect_valid_symbol(symbol), string)
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.item(index))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(|index| self.item(index))
+    }
+}
+
+impl<S> ExactSizeIterator for Iter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
@@ -6,12 +6,55 @@
 
 mod bucket;
 mod buffer;
+mod cstr;
+mod free_list;
+mod indexed_buffer;
+mod mmap;
 mod string;
 
 #[cfg(feature = "backends")]
-pub use self::{bucket::BucketBackend, buffer::BufferBackend, string::StringBackend};
+pub(crate) use self::buffer::validate_buffer;
+#[cfg(all(feature = "std", feature = "backends"))]
+pub(crate) use self::buffer::{decode_var_usize, encode_var_usize};
+#[cfg(feature = "mmap")]
+pub use self::mmap::MmapBackend;
+#[cfg(feature = "backends")]
+pub use self::{
+    bucket::BucketBackend,
+    buffer::{BufferBackend, BytesIter},
+    cstr::CStrBackend,
+    free_list::FreeListBackend,
+    indexed_buffer::IndexedBufferBackend,
+    string::StringBackend,
+};
 use crate::Symbol;
 
+/// Validates that `ends` are the ascending end offsets of the strings within `buffer`.
+///
+/// Used to validate the raw parts of deserialized backends.
+#[cfg(all(feature = "backends", feature = "serde"))]
+fn validate_ends<S>(ends: &[usize], buffer: &str) -> Result<(), &'static str>
+where
+    S: Symbol,
+{
+    if let Some(last) = ends.len().checked_sub(1) {
+        if S::try_from_usize(last).is_none() {
+            return Err("too many strings for the symbol type");
+        }
+    }
+    let mut from = 0;
+    for &to in ends {
+        if to < from || !buffer.is_char_boundary(to) {
+            return Err("invalid string end offset");
+        }
+        from = to;
+    }
+    if from != buffer.len() {
+        return Err("string end offsets do not cover the buffer");
+    }
+    Ok(())
+}
+
 /// The default backend recommended for general use.
 #[cfg(feature = "backends")]
 pub type DefaultBackend = StringBackend<crate::DefaultSymbol>;
@@ -30,6 +73,18 @@ pub trait Backend: Default {
     where
         Self: 'a;
 
+    /// The name identifying the kind of the backend.
+    ///
+    /// Recorded by [`StringInterner::write_to`](`crate::StringInterner::write_to`)
+    /// so that snapshots are only restored into backends of the same kind.
+    const KIND: &'static str = "";
+
+    /// `true` if the backend is read-only and never interns new strings.
+    ///
+    /// Fallible interning then reports [`Error::ReadOnly`](`crate::Error::ReadOnly`)
+    /// instead of [`Error::OutOfSymbols`](`crate::Error::OutOfSymbols`).
+    const READ_ONLY: bool = false;
+
     /// Creates a new backend for the given capacity.
     ///
     /// The capacity denotes how many strings are expected to be interned.
@@ -41,8 +96,27 @@ pub trait Backend: Default {
     ///
     /// The backend must make sure that the returned symbol maps back to the
     /// original string in its [`resolve`](`Backend::resolve`) method.
+    ///
+    /// # Panics
+    ///
+    /// If the backend ran out of symbols.
     fn intern(&mut self, string: &str) -> Self::Symbol;
 
+    /// Tries to intern the given string and returns its symbol.
+    ///
+    /// Returns `None` if the backend ran out of symbols in which case
+    /// the backend must remain unchanged.
+    ///
+    /// # Note
+    ///
+    /// The default implementation simply forwards to [`intern`](`Backend::intern`)
+    /// and therefore panics instead of returning `None`. Backends that may run
+    /// out of symbols should implement this method.
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        Some(self.intern(string))
+    }
+
     /// Interns the given static string and returns its interned ref and symbol.
     ///
     /// # Note
@@ -57,9 +131,63 @@ pub trait Backend: Default {
         self.intern(string)
     }
 
+    /// Tries to intern the given static string and returns its symbol.
+    ///
+    /// Returns `None` if the backend ran out of symbols in which case
+    /// the backend must remain unchanged.
+    #[inline]
+    fn try_intern_static(&mut self, string: &'static str) -> Option<Self::Symbol> {
+        // The default implementation simply forwards to the normal [`try_intern`]
+        // implementation. Backends that can optimize for this use case should
+        // implement this method.
+        self.try_intern(string)
+    }
+
     /// Shrink backend capacity to fit interned symbols exactly.
     fn shrink_to_fit(&mut self);
 
+    /// Moves the interned strings into a read-optimized layout.
+    ///
+    /// Used by [`StringInterner::freeze_compact`](`crate::StringInterner::freeze_compact`)
+    /// once no more strings are going to be interned. All symbols must remain valid.
+    ///
+    /// The default implementation forwards to [`shrink_to_fit`](`Backend::shrink_to_fit`).
+    #[inline]
+    fn pack(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    /// Returns the number of strings the backend can hold without reallocating.
+    ///
+    /// # Note
+    ///
+    /// Backends that do not track their capacity in terms of strings may
+    /// estimate it using the same expected string length that they use
+    /// in [`with_capacity`](`Backend::with_capacity`).
+    ///
+    /// The default implementation conservatively returns `0`.
+    #[inline]
+    fn capacity(&self) -> usize {
+        0
+    }
+
+    /// Reserves capacity for at least `additional` more strings to be interned.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Removes all interned strings from the backend.
+    ///
+    /// Backends should keep the allocated memory for reuse. The default
+    /// implementation replaces the backend with an empty one instead.
+    #[inline]
+    fn clear(&mut self) {
+        *self = Self::with_capacity(0);
+    }
+
     /// Resolves the given symbol to its original string contents.
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str>;
 
@@ -76,4 +204,43 @@ pub trait Backend: Default {
 
     /// Creates an iterator that yields all interned strings and their symbols.
     fn iter(&self) -> Self::Iter<'_>;
+
+    /// Removes the string associated to the given symbol if the backend supports removal.
+    ///
+    /// Returns `false` if the backend does not support removal or if the symbol
+    /// did not refer to an interned string.
+    ///
+    /// Used by [`StringInterner::read_from`](`crate::StringInterner::read_from`) to
+    /// restore the symbols of removed strings. Backends implementing [`RemovableBackend`]
+    /// should forward to [`RemovableBackend::remove`].
+    #[inline]
+    fn try_remove(&mut self, symbol: Self::Symbol) -> bool {
+        let _ = symbol;
+        false
+    }
+}
+
+/// Backends that support removal of interned strings.
+///
+/// Symbols of removed strings may be handed out again by the backend
+/// for subsequently interned strings.
+pub trait RemovableBackend: Backend {
+    /// Removes the string associated to the given symbol from the backend.
+    ///
+    /// Returns `true` if a string has been removed and `false` if the symbol
+    /// did not refer to an interned string.
+    fn remove(&mut self, symbol: Self::Symbol) -> bool;
+}
+
+/// Backends that support truncation to a previous number of interned strings.
+///
+/// Used by [`StringInterner::rollback_to`](`crate::StringInterner::rollback_to`)
+/// to undo speculative interning. The backend must iterate its strings in the
+/// order in which they have been interned.
+pub trait TruncatableBackend: Backend {
+    /// Removes all but the first `len` interned strings from the backend.
+    ///
+    /// Only the symbols of the removed strings are invalidated.
+    /// Does nothing if the backend holds `len` or fewer strings.
+    fn truncate(&mut self, len: usize);
 }
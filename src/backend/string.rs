@@ -1,10 +1,13 @@
 #![cfg(feature = "backends")]
 
-use super::Backend;
+use super::{Backend, TruncatableBackend};
 use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
 use alloc::{string::String, vec::Vec};
 use core::{iter::Enumerate, marker::PhantomData, slice};
 
+/// According to google the approx. word length is 5.
+const DEFAULT_WORD_LEN: usize = 5;
+
 /// An interner backend that accumulates all interned string contents into one string.
 ///
 /// # Note
@@ -95,9 +98,9 @@ impl<S> StringBackend<S>
 where
     S: Symbol,
 {
-    /// Returns the next available symbol.
-    fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.ends.len())
+    /// Returns the next available symbol if any.
+    fn next_symbol(&self) -> Option<S> {
+        S::try_from_usize(self.ends.len())
     }
 
     /// Returns the string associated to the span.
@@ -130,17 +133,40 @@ where
         Span { from, to }
     }
 
-    /// Pushes the given string into the buffer and returns its span.
-    ///
-    /// # Panics
+    /// Pushes the given string into the buffer and returns its symbol.
     ///
-    /// If the backend ran out of symbols.
-    fn push_string(&mut self, string: &str) -> S {
+    /// Returns `None` and leaves the backend unchanged if it ran out of symbols.
+    fn push_string(&mut self, string: &str) -> Option<S> {
+        let symbol = self.next_symbol()?;
         self.buffer.push_str(string);
         let to = self.buffer.len();
-        let symbol = self.next_symbol();
         self.ends.push(to);
-        symbol
+        Some(symbol)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S> StringBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the end offsets of the interned strings and the buffer holding their contents.
+    pub(crate) fn as_raw_parts(&self) -> (&[usize], &str) {
+        (&self.ends, &self.buffer)
+    }
+
+    /// Creates a backend from the end offsets of its strings and the buffer holding their contents.
+    ///
+    /// # Errors
+    ///
+    /// If the raw parts do not form a valid backend.
+    pub(crate) fn from_raw_parts(ends: Vec<usize>, buffer: String) -> Result<Self, &'static str> {
+        super::validate_ends::<S>(&ends, &buffer)?;
+        Ok(Self {
+            ends,
+            buffer,
+            marker: Default::default(),
+        })
     }
 }
 
@@ -153,20 +179,24 @@ where
         = Iter<'a, S>
     where
         Self: 'a;
+    const KIND: &'static str = "string";
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn with_capacity(cap: usize) -> Self {
-        // According to google the approx. word length is 5.
-        let default_word_len = 5;
         Self {
             ends: Vec::with_capacity(cap),
-            buffer: String::with_capacity(cap * default_word_len),
+            buffer: String::with_capacity(cap * DEFAULT_WORD_LEN),
             marker: Default::default(),
         }
     }
 
     #[inline]
     fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.try_intern(string).expect("encountered invalid symbol")
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
         self.push_string(string)
     }
 
@@ -181,6 +211,22 @@ where
         self.buffer.shrink_to_fit();
     }
 
+    #[inline]
+    fn capacity(&self) -> usize {
+        let remaining = (self.buffer.capacity() - self.buffer.len()) / DEFAULT_WORD_LEN;
+        usize::min(self.ends.capacity(), self.ends.len() + remaining)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.ends.reserve(additional);
+        self.buffer.reserve(additional * DEFAULT_WORD_LEN);
+    }
+
+    fn clear(&mut self) {
+        self.ends.clear();
+        self.buffer.clear();
+    }
+
     #[inline]
     unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
         // SAFETY: The function is marked unsafe so that the caller guarantees
@@ -194,6 +240,20 @@ where
     }
 }
 
+impl<S> TruncatableBackend for StringBackend<S>
+where
+    S: Symbol,
+{
+    fn truncate(&mut self, len: usize) {
+        if len >= self.ends.len() {
+            return;
+        }
+        let to = len.checked_sub(1).map(|last| self.ends[last]).unwrap_or(0);
+        self.buffer.truncate(to);
+        self.ends.truncate(len);
+    }
+}
+
 impl<'a, S> IntoIterator for &'a StringBackend<S>
 where
     S: Symbol,
@@ -245,4 +305,20 @@ where
             )
         })
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (id, &to) = self.ends.nth(n)?;
+        let from = self
+            .backend
+            .ends
+            .get(id.wrapping_sub(1))
+            .copied()
+            .unwrap_or(0);
+        self.start = to;
+        Some((
+            expect_valid_symbol(id),
+            self.backend.span_to_str(Span { from, to }),
+        ))
+    }
 }
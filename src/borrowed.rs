@@ -0,0 +1,390 @@
+use crate::{
+    interner::{get_dedup, get_or_intern_dedup, make_hash},
+    symbol::expect_valid_symbol,
+    DefaultSymbol,
+    Error,
+    Symbol,
+};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    iter::Enumerate,
+    marker::PhantomData,
+    slice,
+};
+use hashbrown::{DefaultHashBuilder, HashMap};
+
+/// A string interner that borrows its interned strings instead of owning them.
+///
+/// All strings interned by a `BorrowedInterner<'a>` must outlive `'a` and
+/// resolve to `&'a str`. Since no string data is ever copied this is useful
+/// to look up strings of a large input that is kept alive anyways, for example
+/// by deserializing from a borrowed input or via [`BorrowedInterner::from_buffer`].
+///
+/// Symbols are assigned contiguously in the order in which strings are interned,
+/// except for interners created by [`BorrowedInterner::from_buffer`] whose symbols
+/// are the byte offsets of the strings within the buffer.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::BorrowedInterner;
+/// let input = String::from("Tiger Horse Tiger");
+/// let mut interner = <BorrowedInterner>::new();
+/// let symbols = input
+///     .split(' ')
+///     .map(|word| interner.get_or_intern(word))
+///     .collect::<Vec<_>>();
+/// assert_eq!(interner.len(), 2);
+/// assert_eq!(symbols[0], symbols[2]);
+/// assert_eq!(interner.resolve(symbols[1]), Some("Horse"));
+/// ```
+pub struct BorrowedInterner<'a, S = DefaultSymbol, H = DefaultHashBuilder> {
+    dedup: HashMap<S, (), ()>,
+    hasher: H,
+    /// The interned strings in the order in which they have been interned.
+    strings: Vec<&'a str>,
+    /// The symbols of the interned strings if they are not contiguous.
+    ///
+    /// These are the byte offsets of the strings in the layout of the
+    /// [`BufferBackend`](`crate::backend::BufferBackend`).
+    offsets: Option<Vec<usize>>,
+}
+
+impl<S, H> Debug for BorrowedInterner<'_, S, H>
+where
+    S: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BorrowedInterner")
+            .field("strings", &self.strings)
+            .finish()
+    }
+}
+
+impl<S, H> Clone for BorrowedInterner<'_, S, H>
+where
+    S: Symbol,
+    H: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            strings: self.strings.clone(),
+            offsets: self.offsets.clone(),
+        }
+    }
+}
+
+impl<S, H> PartialEq for BorrowedInterner<'_, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.strings == rhs.strings && self.offsets == rhs.offsets
+    }
+}
+
+impl<S, H> Eq for BorrowedInterner<'_, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S, H> Default for BorrowedInterner<'_, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, S, H> BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `BorrowedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(H::default())
+    }
+
+    /// Creates a new `BorrowedInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, H::default())
+    }
+
+    /// Creates a new `BorrowedInterner` over the strings of the given `buffer`
+    /// without copying them.
+    ///
+    /// The buffer must be in the layout of the [`BufferBackend`](`crate::backend::BufferBackend`),
+    /// that is a sequence of UTF-8 encoded strings that are each prefixed by
+    /// their `var7` encoded length in bytes.
+    ///
+    /// Like for the [`BufferBackend`](`crate::backend::BufferBackend`) the symbols
+    /// of the strings are their byte offsets within the buffer. Therefore the symbols
+    /// of the [`StringInterner`](`crate::StringInterner`) that wrote the buffer
+    /// remain valid. Strings that are interned afterwards are assigned the offsets
+    /// they would have if they were appended to the buffer.
+    ///
+    /// # Note
+    ///
+    /// Resolving symbols of such an interner requires a binary search.
+    ///
+    /// # Errors
+    ///
+    /// - If the buffer is malformed.
+    /// - If the buffer holds the same string more than once.
+    /// - If the buffer holds more strings than there are symbols.
+    #[cfg(feature = "backends")]
+    pub fn from_buffer(buffer: &'a [u8]) -> Result<Self, Error> {
+        let mut strings = Vec::new();
+        crate::backend::validate_buffer(buffer, |_, string| strings.push(string))
+            .map_err(|_| Error::InvalidBuffer)?;
+        let mut interner = Self::with_capacity(strings.len());
+        interner.offsets = Some(Vec::with_capacity(strings.len()));
+        for string in strings {
+            let (_, is_new) = interner.try_get_or_intern_with_status(string)?;
+            if !is_new {
+                return Err(Error::DuplicateString);
+            }
+        }
+        Ok(interner)
+    }
+}
+
+impl<'a, S, H> BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `BorrowedInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Creates a new empty `BorrowedInterner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            hasher: hash_builder,
+            strings: Vec::with_capacity(cap),
+            offsets: None,
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol of the string with the given `index`.
+    #[inline]
+    fn symbol_of(&self, index: S) -> S {
+        match &self.offsets {
+            None => index,
+            Some(offsets) => expect_valid_symbol(offsets[index.to_usize()]),
+        }
+    }
+
+    /// Returns the index of the string with the given `symbol` if any.
+    #[inline]
+    fn index_of(&self, symbol: S) -> Option<usize> {
+        match &self.offsets {
+            None => Some(symbol.to_usize()),
+            Some(offsets) => offsets.binary_search(&symbol.to_usize()).ok(),
+        }
+    }
+
+    /// Returns the symbol for the next interned string if any.
+    fn next_symbol(&self) -> Option<S> {
+        let Some(offsets) = &self.offsets else {
+            return S::try_from_usize(self.strings.len());
+        };
+        let next_offset = match (offsets.last(), self.strings.last()) {
+            (Some(&offset), Some(string)) => {
+                // The number of bytes of the `var7` encoded length of the string.
+                let len_bits = usize::BITS - string.len().leading_zeros();
+                let len_bytes = usize::max(len_bits.div_ceil(7) as usize, 1);
+                offset.checked_add(len_bytes)?.checked_add(string.len())?
+            }
+            _ => 0,
+        };
+        S::try_from_usize(next_offset)
+    }
+
+    /// Returns the symbol for the given string if any.
+    pub fn get<T>(&self, string: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            strings,
+            ..
+        } = self;
+        let hash = make_hash(hasher, string);
+        get_dedup(dedup, hash, |index| string == strings[index.to_usize()])
+            .map(|index| self.symbol_of(index))
+    }
+
+    /// Interns the given string without copying it.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern(&mut self, string: &'a str) -> S {
+        self.try_get_or_intern(string)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given string without copying it.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern(&mut self, string: &'a str) -> Result<S, Error> {
+        self.try_get_or_intern_with_status(string)
+            .map(|(symbol, _)| symbol)
+    }
+
+    /// Interns the given string without copying it.
+    ///
+    /// Returns its symbol and `true` if the string has not been interned before.
+    fn try_get_or_intern_with_status(&mut self, string: &'a str) -> Result<(S, bool), Error> {
+        let next_symbol = self.next_symbol();
+        let Self {
+            dedup,
+            hasher,
+            strings,
+            offsets,
+        } = self;
+        let hash = make_hash(hasher, string);
+        let (index, is_new) = get_or_intern_dedup(
+            dedup,
+            strings,
+            hash,
+            |strings, index| string == strings[index.to_usize()],
+            |strings| {
+                let symbol = next_symbol.ok_or(Error::OutOfSymbols)?;
+                // The index never exceeds the symbol and therefore is valid as well.
+                let index = expect_valid_symbol(strings.len());
+                strings.push(string);
+                if let Some(offsets) = offsets {
+                    offsets.push(symbol.to_usize());
+                }
+                Ok(index)
+            },
+            |strings, index| make_hash(hasher, strings[index.to_usize()]),
+        )?;
+        Ok((self.symbol_of(index), is_new))
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    ///
+    /// The returned string is borrowed for the lifetime of the interned strings
+    /// instead of the lifetime of the interner.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&'a str> {
+        self.strings.get(self.index_of(symbol)?).copied()
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> BorrowedIter<'_, 'a, S> {
+        BorrowedIter::new(self)
+    }
+}
+
+impl<'i, 'a, S, H> IntoIterator for &'i BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Item = (S, &'a str);
+    type IntoIter = BorrowedIter<'i, 'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the strings and symbols of a [`BorrowedInterner`].
+pub struct BorrowedIter<'i, 'a, S> {
+    strings: Enumerate<slice::Iter<'i, &'a str>>,
+    offsets: Option<&'i [usize]>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'i, 'a, S> BorrowedIter<'i, 'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn new<H>(interner: &'i BorrowedInterner<'a, S, H>) -> Self {
+        Self {
+            strings: interner.strings.iter().enumerate(),
+            offsets: interner.offsets.as_deref(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, S> Iterator for BorrowedIter<'_, 'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.strings.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let offsets = self.offsets;
+        self.strings.next().map(|(id, &string)| {
+            let symbol = offsets.map_or(id, |offsets| offsets[id]);
+            (expect_valid_symbol(symbol), string)
+        })
+    }
+}
+
+impl<S> ExactSizeIterator for BorrowedIter<'_, '_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.strings.len()
+    }
+}
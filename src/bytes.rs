@@ -0,0 +1,310 @@
+#![cfg(feature = "backends")]
+
+use crate::{
+    backend::{Backend, BufferBackend, BytesIter},
+    interner::{get_dedup, get_or_intern_dedup, make_hash},
+    DefaultSymbol,
+    Error,
+    Symbol,
+};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    iter::FromIterator,
+};
+use hashbrown::{DefaultHashBuilder, HashMap};
+
+/// Data structure to intern and resolve byte strings that need not be UTF-8 encoded.
+///
+/// Works like the [`StringInterner`](`crate::StringInterner`) but for `[u8]`.
+///
+/// # Note
+///
+/// The byte strings are stored in the length prefixed layout of the [`BufferBackend`].
+/// Therefore the symbols of the interner are not contiguous.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::BytesInterner;
+/// let mut interner = <BytesInterner>::new();
+/// let invalid = interner.get_or_intern(b"\xFF\xFE");
+/// let tiger = interner.get_or_intern("Tiger");
+/// assert_eq!(interner.get_or_intern([0xFF, 0xFE]), invalid);
+/// assert_eq!(interner.resolve(invalid), Some(&b"\xFF\xFE"[..]));
+/// assert_eq!(interner.resolve(tiger), Some(&b"Tiger"[..]));
+/// ```
+pub struct BytesInterner<S = DefaultSymbol, H = DefaultHashBuilder>
+where
+    S: Symbol,
+{
+    dedup: HashMap<S, (), ()>,
+    hasher: H,
+    /// Holds the interned byte strings and therefore is never used as `Backend`.
+    backend: BufferBackend<S>,
+}
+
+impl<S, H> Debug for BytesInterner<S, H>
+where
+    S: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BytesInterner")
+            .field("dedup", &self.dedup)
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl<S, H> Clone for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<S, H> PartialEq for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.len() == rhs.len() && self.backend == rhs.backend
+    }
+}
+
+impl<S, H> Eq for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S, H> Default for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `BytesInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(H::default())
+    }
+
+    /// Creates a new `BytesInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, H::default())
+    }
+}
+
+impl<S, H> BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `BytesInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::default(),
+            hasher: hash_builder,
+            backend: BufferBackend::default(),
+        }
+    }
+
+    /// Creates a new empty `BytesInterner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            hasher: hash_builder,
+            backend: BufferBackend::with_capacity(cap),
+        }
+    }
+
+    /// Returns the number of byte strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.dedup.len()
+    }
+
+    /// Returns `true` if the interner has no interned byte strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given byte string if any.
+    pub fn get<T>(&self, bytes: T) -> Option<S>
+    where
+        T: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            backend,
+        } = self;
+        let hash = make_hash(hasher, bytes);
+        get_dedup(dedup, hash, |symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            bytes == unsafe { backend.resolve_bytes_unchecked(symbol) }
+        })
+    }
+
+    /// Interns the given byte string.
+    ///
+    /// Returns a symbol for resolution into the original byte string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of byte strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, bytes: T) -> S
+    where
+        T: AsRef<[u8]>,
+    {
+        self.try_get_or_intern(bytes)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given byte string.
+    ///
+    /// Returns a symbol for resolution into the original byte string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of byte strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    pub fn try_get_or_intern<T>(&mut self, bytes: T) -> Result<S, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            backend,
+        } = self;
+        let hash = make_hash(hasher, bytes);
+        let (symbol, _) = get_or_intern_dedup(
+            dedup,
+            backend,
+            hash,
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                bytes == unsafe { backend.resolve_bytes_unchecked(symbol) }
+            },
+            |backend| backend.try_intern_bytes(bytes).ok_or(Error::OutOfSymbols),
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                let bytes = unsafe { backend.resolve_bytes_unchecked(symbol) };
+                make_hash(hasher, bytes)
+            },
+        )?;
+        Ok(symbol)
+    }
+
+    /// Shrink backend capacity to fit the interned byte strings exactly.
+    pub fn shrink_to_fit(&mut self) {
+        self.backend.shrink_to_fit()
+    }
+
+    /// Returns the byte string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&[u8]> {
+        self.backend.resolve_bytes(symbol)
+    }
+
+    /// Returns the byte string for the given `symbol` without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`BytesInterner`].
+    #[inline]
+    pub unsafe fn resolve_unchecked(&self, symbol: S) -> &[u8] {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.backend.resolve_bytes_unchecked(symbol) }
+    }
+
+    /// Returns an iterator that yields all interned byte strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> BytesIter<'_, S> {
+        self.backend.iter_bytes()
+    }
+}
+
+impl<S, H, T> FromIterator<T> for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+    T: AsRef<[u8]>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let (capacity, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<S, H, T> Extend<T> for BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+    T: AsRef<[u8]>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for bytes in iter {
+            self.get_or_intern(bytes);
+        }
+    }
+}
+
+impl<'a, S, H> IntoIterator for &'a BytesInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Item = (S, &'a [u8]);
+    type IntoIter = BytesIter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
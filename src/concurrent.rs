@@ -0,0 +1,313 @@
+#![cfg(all(feature = "std", feature = "backends"))]
+
+use crate::{
+    backend::{Backend, BucketBackend},
+    interner::{insert_dedup, make_hash},
+    normalize::Identity,
+    DefaultHashBuilder,
+    DefaultSymbol,
+    Error,
+    Symbol,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    marker::PhantomData,
+};
+use hashbrown::HashMap;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// The number of shards used by [`ConcurrentStringInterner::new`].
+const DEFAULT_SHARDS: usize = 16;
+
+/// A string interner that can be shared between threads.
+///
+/// The interned strings are distributed over a number of independently locked
+/// shards by their hash value. Therefore threads interning or resolving strings
+/// of different shards do not block each other.
+///
+/// The symbols are globally unique across all shards. Unlike the symbols of a
+/// [`StringInterner`](`crate::StringInterner`) they are not contiguous.
+///
+/// # Note
+///
+/// Every shard is backed by a [`BucketBackend`] since its stable references
+/// allow to resolve symbols to strings that outlive the shard's lock guard.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::ConcurrentStringInterner;
+/// let interner = <ConcurrentStringInterner>::new();
+/// let symbols = std::thread::scope(|scope| {
+///     let tiger = scope.spawn(|| interner.get_or_intern("Tiger"));
+///     let horse = scope.spawn(|| interner.get_or_intern("Horse"));
+///     [tiger.join().unwrap(), horse.join().unwrap()]
+/// });
+/// assert_eq!(interner.get_or_intern("Tiger"), symbols[0]);
+/// assert_eq!(interner.resolve(symbols[1]), Some("Horse"));
+/// assert_eq!(interner.len(), 2);
+/// ```
+pub struct ConcurrentStringInterner<S = DefaultSymbol, H = DefaultHashBuilder>
+where
+    S: Symbol,
+{
+    shards: Box<[RwLock<Shard<S>>]>,
+    hasher: H,
+    marker: PhantomData<fn() -> S>,
+}
+
+/// A shard of the [`ConcurrentStringInterner`].
+///
+/// The symbols of a shard are local to the shard.
+struct Shard<S>
+where
+    S: Symbol,
+{
+    dedup: HashMap<S, (), ()>,
+    backend: BucketBackend<S>,
+}
+
+impl<S> Shard<S>
+where
+    S: Symbol,
+{
+    /// Returns the local symbol of the `string` with the given `hash` if any.
+    fn get(&self, hash: u64, string: &str) -> Option<S> {
+        let Self { dedup, backend } = self;
+        dedup
+            .raw_entry()
+            .from_hash(hash, |symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                string == unsafe { backend.resolve_unchecked(*symbol) }
+            })
+            .map(|(&symbol, &())| symbol)
+    }
+}
+
+impl<S, H> Debug for ConcurrentStringInterner<S, H>
+where
+    S: Symbol,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrentStringInterner")
+            .field("shards", &self.shards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, H> Default for ConcurrentStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> ConcurrentStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `ConcurrentStringInterner` with a default number of shards.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_shards(DEFAULT_SHARDS)
+    }
+
+    /// Creates a new empty `ConcurrentStringInterner` with the given number of shards.
+    ///
+    /// # Panics
+    ///
+    /// If `shards` is zero.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_shards(shards: usize) -> Self {
+        Self::with_shards_and_hasher(shards, H::default())
+    }
+}
+
+impl<S, H> ConcurrentStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ConcurrentStringInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self::with_shards_and_hasher(DEFAULT_SHARDS, hash_builder)
+    }
+
+    /// Creates a new empty `ConcurrentStringInterner` with the given number of shards
+    /// and the given hasher.
+    ///
+    /// # Panics
+    ///
+    /// If `shards` is zero.
+    pub fn with_shards_and_hasher(shards: usize, hash_builder: H) -> Self {
+        assert!(
+            shards > 0,
+            "a concurrent string interner requires at least one shard"
+        );
+        let shards = (0..shards)
+            .map(|_| {
+                RwLock::new(Shard {
+                    dedup: HashMap::default(),
+                    backend: BucketBackend::default(),
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        Self {
+            shards,
+            hasher: hash_builder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of shards of the interner.
+    #[inline]
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the number of strings interned by the interner.
+    ///
+    /// # Note
+    ///
+    /// Strings that are concurrently interned by other threads may or may not be counted.
+    pub fn len(&self) -> usize {
+        (0..self.shards.len())
+            .map(|index| self.read_shard(index).dedup.len())
+            .sum()
+    }
+
+    /// Returns `true` if the string interner has no interned strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index of the shard for the given `hash`.
+    #[inline]
+    fn shard_index(&self, hash: u64) -> usize {
+        // The low bits of the hash select the bucket within the shard's table.
+        // Using the high bits for the shard keeps the shards' tables well balanced.
+        ((hash >> 32) as usize) % self.shards.len()
+    }
+
+    /// Locks the shard at `index` for reading.
+    #[inline]
+    fn read_shard(&self, index: usize) -> RwLockReadGuard<'_, Shard<S>> {
+        self.shards[index]
+            .read()
+            .expect("encountered poisoned shard lock")
+    }
+
+    /// Locks the shard at `index` for writing.
+    #[inline]
+    fn write_shard(&self, index: usize) -> RwLockWriteGuard<'_, Shard<S>> {
+        self.shards[index]
+            .write()
+            .expect("encountered poisoned shard lock")
+    }
+
+    /// Returns the global symbol for the `local` symbol of the shard at `index` if any.
+    #[inline]
+    fn to_global(&self, index: usize, local: usize) -> Option<S> {
+        local
+            .checked_mul(self.shards.len())
+            .and_then(|global| global.checked_add(index))
+            .and_then(S::try_from_usize)
+    }
+
+    /// Returns the symbol for the given string if any.
+    ///
+    /// Can be used to query if a string has already been interned without interning.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let hash = make_hash(&self.hasher, string);
+        let index = self.shard_index(hash);
+        let local = self.read_shard(index).get(hash, string)?;
+        self.to_global(index, local.to_usize())
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&self, string: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        self.try_get_or_intern(string)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    pub fn try_get_or_intern<T>(&self, string: T) -> Result<S, Error>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let hash = make_hash(&self.hasher, string);
+        let index = self.shard_index(hash);
+        // Most strings are interned already so we try a shared look-up first.
+        if let Some(local) = self.read_shard(index).get(hash, string) {
+            return self
+                .to_global(index, local.to_usize())
+                .ok_or(Error::OutOfSymbols);
+        }
+        let mut shard = self.write_shard(index);
+        // The string might have been interned by another thread in the meantime.
+        if let Some(local) = shard.get(hash, string) {
+            return self
+                .to_global(index, local.to_usize())
+                .ok_or(Error::OutOfSymbols);
+        }
+        let symbol = self
+            .to_global(index, shard.dedup.len())
+            .ok_or(Error::OutOfSymbols)?;
+        let Shard { dedup, backend } = &mut *shard;
+        let local = backend.try_intern(string).ok_or(Error::OutOfSymbols)?;
+        debug_assert!(self.to_global(index, local.to_usize()) == Some(symbol));
+        insert_dedup::<_, _, Identity>(dedup, &self.hasher, backend, hash, local);
+        Ok(symbol)
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let symbol = symbol.to_usize();
+        let index = symbol % self.shards.len();
+        let local = S::try_from_usize(symbol / self.shards.len())?;
+        let shard = self.read_shard(index);
+        let string: *const str = shard.backend.resolve(local)?;
+        // SAFETY: The bucket backend never moves or deallocates its interned
+        //         strings while it is alive and the concurrent interner never
+        //         removes strings from its shards. Therefore the string lives
+        //         as long as `self` even after the lock guard has been dropped.
+        Some(unsafe { &*string })
+    }
+}
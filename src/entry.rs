@@ -0,0 +1,199 @@
+use crate::{
+    backend::Backend,
+    interner::make_normalized_hash,
+    normalize::{Identity, Normalizer},
+    Error,
+};
+use core::{hash::BuildHasher, marker::PhantomData};
+use hashbrown::hash_map::RawVacantEntryMut;
+
+/// A view into a single string of a [`StringInterner`](`crate::StringInterner`),
+/// which may either be interned already or not.
+///
+/// This is constructed by [`StringInterner::entry`](`crate::StringInterner::entry`).
+pub enum Entry<'a, B, H, N = Identity>
+where
+    B: Backend,
+{
+    /// The string is already interned.
+    Occupied(OccupiedEntry<'a, B>),
+    /// The string is not yet interned.
+    Vacant(VacantEntry<'a, B, H, N>),
+}
+
+/// A view into an already interned string of a [`StringInterner`](`crate::StringInterner`).
+///
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, B>
+where
+    B: Backend,
+{
+    symbol: <B as Backend>::Symbol,
+    string: &'a str,
+}
+
+/// A view into a not yet interned string of a [`StringInterner`](`crate::StringInterner`).
+///
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, B, H, N = Identity>
+where
+    B: Backend,
+{
+    vacant: RawVacantEntryMut<'a, <B as Backend>::Symbol, (), ()>,
+    backend: &'a mut B,
+    hasher: &'a H,
+    hash: u64,
+    string: &'a str,
+    normalizer: PhantomData<fn() -> N>,
+}
+
+impl<'a, B, H, N> Entry<'a, B, H, N>
+where
+    B: Backend,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Returns the string of the entry.
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        match self {
+            Self::Occupied(occupied) => occupied.key(),
+            Self::Vacant(vacant) => vacant.key(),
+        }
+    }
+
+    /// Returns the symbol of the entry if the string is already interned.
+    #[inline]
+    pub fn symbol(&self) -> Option<<B as Backend>::Symbol> {
+        match self {
+            Self::Occupied(occupied) => Some(occupied.symbol()),
+            Self::Vacant(_) => None,
+        }
+    }
+
+    /// Interns the string of the entry if it is not yet interned.
+    ///
+    /// Returns the symbol of the string in either case.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn or_intern(self) -> <B as Backend>::Symbol {
+        match self {
+            Self::Occupied(occupied) => occupied.symbol(),
+            Self::Vacant(vacant) => vacant.intern(),
+        }
+    }
+
+    /// Interns the string of the entry if it is not yet interned.
+    ///
+    /// Returns the symbol of the string in either case.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_or_intern(self) -> Result<<B as Backend>::Symbol, Error> {
+        match self {
+            Self::Occupied(occupied) => Ok(occupied.symbol()),
+            Self::Vacant(vacant) => vacant.try_intern(),
+        }
+    }
+}
+
+impl<'a, B> OccupiedEntry<'a, B>
+where
+    B: Backend,
+{
+    /// Creates a new occupied entry for the interned `string` and its `symbol`.
+    #[inline]
+    pub(crate) fn new(symbol: <B as Backend>::Symbol, string: &'a str) -> Self {
+        Self { symbol, string }
+    }
+
+    /// Returns the string of the entry.
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.string
+    }
+
+    /// Returns the symbol of the interned string.
+    #[inline]
+    pub fn symbol(&self) -> <B as Backend>::Symbol {
+        self.symbol
+    }
+}
+
+impl<'a, B, H, N> VacantEntry<'a, B, H, N>
+where
+    B: Backend,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Creates a new vacant entry for the `string` with the given `hash`.
+    #[inline]
+    pub(crate) fn new(
+        vacant: RawVacantEntryMut<'a, <B as Backend>::Symbol, (), ()>,
+        backend: &'a mut B,
+        hasher: &'a H,
+        hash: u64,
+        string: &'a str,
+    ) -> Self {
+        Self {
+            vacant,
+            backend,
+            hasher,
+            hash,
+            string,
+            normalizer: PhantomData,
+        }
+    }
+
+    /// Returns the string of the entry.
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.string
+    }
+
+    /// Interns the string of the entry and returns its symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn intern(self) -> <B as Backend>::Symbol {
+        self.try_intern().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the string of the entry and returns its symbol.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_intern(self) -> Result<<B as Backend>::Symbol, Error> {
+        let Self {
+            vacant,
+            backend,
+            hasher,
+            hash,
+            string,
+            ..
+        } = self;
+        let symbol = backend
+            .try_intern(string)
+            .ok_or_else(Error::intern_failed::<B>)?;
+        vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            let string = unsafe { backend.resolve_unchecked(*symbol) };
+            make_normalized_hash::<N>(hasher, string)
+        });
+        Ok(symbol)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::backend::Backend;
+use core::fmt;
+
+/// Errors that may occur when using the fallible APIs of the
+/// [`StringInterner`](`crate::StringInterner`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The interner ran out of symbols for the chosen symbol type.
+    OutOfSymbols,
+    /// The input holds the same string more than once.
+    DuplicateString,
+    /// The input buffer is malformed.
+    InvalidBuffer,
+    /// The interner backend is read-only and cannot intern new strings.
+    ReadOnly,
+}
+
+impl Error {
+    /// Returns the error for a backend of type `B` that failed to intern a string.
+    #[inline]
+    pub(crate) fn intern_failed<B>() -> Self
+    where
+        B: Backend,
+    {
+        if B::READ_ONLY {
+            Self::ReadOnly
+        } else {
+            Self::OutOfSymbols
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfSymbols => f.write_str("the interner ran out of symbols"),
+            Self::DuplicateString => f.write_str("encountered duplicate strings"),
+            Self::InvalidBuffer => f.write_str("encountered malformed buffer"),
+            Self::ReadOnly => f.write_str("the interner backend is read-only"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
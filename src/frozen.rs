@@ -0,0 +1,176 @@
+use crate::{
+    backend::Backend,
+    normalize::{Identity, Normalizer},
+    StringInterner,
+    Symbol,
+};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+};
+use hashbrown::DefaultHashBuilder;
+
+/// A read-only snapshot of a [`StringInterner`].
+///
+/// This is constructed by [`StringInterner::freeze`] or [`StringInterner::freeze_compact`].
+///
+/// Since a frozen interner cannot intern new strings all of its methods take `&self`.
+/// It is [`Sync`] whenever its backend and hasher are, so that it can be shared
+/// between threads behind an [`Arc`](`alloc::sync::Arc`) without any locking.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::DefaultStringInterner;
+/// # use std::sync::Arc;
+/// let mut interner = DefaultStringInterner::default();
+/// let tiger = interner.get_or_intern("Tiger");
+/// let frozen = Arc::new(interner.freeze());
+/// let resolved = std::thread::spawn({
+///     let frozen = Arc::clone(&frozen);
+///     move || frozen.resolve(tiger).map(str::to_owned)
+/// });
+/// assert_eq!(resolved.join().unwrap().as_deref(), Some("Tiger"));
+/// assert_eq!(frozen.get("Tiger"), Some(tiger));
+/// ```
+pub struct FrozenInterner<B, H = DefaultHashBuilder, N = Identity>
+where
+    B: Backend,
+{
+    interner: StringInterner<B, H, N>,
+}
+
+impl<B, H, N> Debug for FrozenInterner<B, H, N>
+where
+    B: Backend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenInterner")
+            .field("interner", &self.interner)
+            .finish()
+    }
+}
+
+impl<B, H, N> Clone for FrozenInterner<B, H, N>
+where
+    B: Backend + Clone,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interner: self.interner.clone(),
+        }
+    }
+}
+
+impl<B, H, N> PartialEq for FrozenInterner<B, H, N>
+where
+    B: Backend + PartialEq,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.interner == rhs.interner
+    }
+}
+
+impl<B, H, N> Eq for FrozenInterner<B, H, N>
+where
+    B: Backend + Eq,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<B, H, N> From<StringInterner<B, H, N>> for FrozenInterner<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    #[inline]
+    fn from(interner: StringInterner<B, H, N>) -> Self {
+        interner.freeze()
+    }
+}
+
+impl<B, H, N> FrozenInterner<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Creates a new `FrozenInterner` from the given `interner`.
+    #[inline]
+    pub(crate) fn new(interner: StringInterner<B, H, N>) -> Self {
+        Self { interner }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    /// Returns `true` if the interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Returns the symbol for the given string if any.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.get(string)
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns the string for the given `symbol` without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`FrozenInterner`].
+    #[inline]
+    pub unsafe fn resolve_unchecked(&self, symbol: <B as Backend>::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.interner.resolve_unchecked(symbol) }
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
+        self.interner.iter()
+    }
+}
+
+impl<'a, B, H, N> IntoIterator for &'a FrozenInterner<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+    type IntoIter = <B as Backend>::Iter<'a>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
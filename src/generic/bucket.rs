@@ -0,0 +1,300 @@
+#![cfg(feature = "backends")]
+
+use super::GenericBackend;
+use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    iter::Enumerate,
+    marker::PhantomData,
+    ptr::NonNull,
+    slice,
+};
+
+/// An interner backend that reduces memory allocations by using slice buckets.
+///
+/// This is the counterpart of the [`BucketBackend`](`crate::backend::BucketBackend`)
+/// for slices `[U]`. Interned slices are never moved in memory.
+///
+/// # Usage Hint
+///
+/// Use when deallocations or copy overhead is costly.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty interner.
+/// - **Resolve:** Efficiency of interned slice look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned slices.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **best** |
+/// | Allocations | **good** |
+/// | Footprint   | **ok**   |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **best** |
+pub struct BucketSliceBackend<U, S = DefaultSymbol> {
+    /// The interned slices which point into `head` or `full`.
+    spans: Vec<NonNull<[U]>>,
+    /// The bucket that is filled by newly interned slices.
+    ///
+    /// Its capacity is never exceeded so that it never reallocates.
+    head: Vec<U>,
+    full: Vec<Vec<U>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+/// # Safety
+///
+/// The bucket slice backend requires a manual [`Send`] impl because it is self
+/// referential. When cloning a bucket slice backend a deep clone is performed
+/// and all references to itself are updated for the clone.
+unsafe impl<U, S> Send for BucketSliceBackend<U, S>
+where
+    U: Send,
+    S: Symbol,
+{
+}
+
+/// # Safety
+///
+/// The bucket slice backend requires a manual [`Sync`] impl because it is self
+/// referential. Those references won't escape its own scope and also
+/// the bucket slice backend has no interior mutability.
+unsafe impl<U, S> Sync for BucketSliceBackend<U, S>
+where
+    U: Sync,
+    S: Symbol,
+{
+}
+
+impl<U, S> Default for BucketSliceBackend<U, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            spans: Vec::new(),
+            head: Vec::new(),
+            full: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<U, S> BucketSliceBackend<U, S> {
+    /// Returns the interned slice of the given span.
+    #[inline]
+    fn span_to_slice(&self, span: NonNull<[U]>) -> &[U] {
+        // SAFETY: This is safe since all spans point into the buckets of this
+        //         backend whose contents are never moved or mutated while the
+        //         backend is alive.
+        unsafe { span.as_ref() }
+    }
+
+    /// Replaces the head bucket with a new bucket of the given capacity.
+    fn replace_head(&mut self, new_cap: usize) {
+        let new_head = Vec::with_capacity(new_cap);
+        let old_head = core::mem::replace(&mut self.head, new_head);
+        self.full.push(old_head);
+    }
+}
+
+impl<U, S> BucketSliceBackend<U, S>
+where
+    U: Clone,
+{
+    /// Copies the given slice into the head bucket and returns a span to it.
+    fn alloc(&mut self, slice: &[U]) -> NonNull<[U]> {
+        let cap = self.head.capacity();
+        if cap - self.head.len() < slice.len() {
+            let new_cap = (usize::max(cap, slice.len()) + 1).next_power_of_two();
+            self.replace_head(new_cap);
+        }
+        let len = self.head.len();
+        let cap = self.head.capacity();
+        self.head.extend_from_slice(slice);
+        debug_assert_eq!(self.head.capacity(), cap);
+        // SAFETY: The head bucket did not reallocate since its capacity suffices.
+        //         Therefore its pointer stays valid for all of its contents.
+        let data = unsafe { NonNull::new_unchecked(self.head.as_mut_ptr().add(len)) };
+        NonNull::slice_from_raw_parts(data, slice.len())
+    }
+}
+
+impl<U, S> GenericBackend<[U]> for BucketSliceBackend<U, S>
+where
+    U: Clone,
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, U, S>
+    where
+        Self: 'a,
+        U: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            spans: Vec::with_capacity(cap),
+            head: Vec::with_capacity(cap),
+            full: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn try_intern(&mut self, slice: &[U]) -> Option<Self::Symbol> {
+        let symbol = S::try_from_usize(self.spans.len())?;
+        let span = self.alloc(slice);
+        self.spans.push(span);
+        Some(symbol)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        // The head bucket must not be shrunk since this invalidates its spans.
+        self.spans.shrink_to_fit();
+        self.full.shrink_to_fit();
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&[U]> {
+        self.spans
+            .get(symbol.to_usize())
+            .map(|&span| self.span_to_slice(span))
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &[U] {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let span = unsafe { *self.spans.get_unchecked(symbol.to_usize()) };
+        self.span_to_slice(span)
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<U, S> Debug for BucketSliceBackend<U, S>
+where
+    U: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.spans.iter().map(|&span| self.span_to_slice(span)))
+            .finish()
+    }
+}
+
+impl<U, S> Clone for BucketSliceBackend<U, S>
+where
+    U: Clone,
+{
+    fn clone(&self) -> Self {
+        // For performance reasons we copy all cloned slices into a single cloned
+        // head bucket leaving the cloned `full` empty.
+        let new_head_cap = self.head.capacity() + self.full.iter().map(Vec::len).sum::<usize>();
+        let mut clone = Self {
+            spans: Vec::with_capacity(self.spans.len()),
+            head: Vec::with_capacity(new_head_cap),
+            full: Vec::new(),
+            marker: Default::default(),
+        };
+        for &span in &self.spans {
+            let span = clone.alloc(self.span_to_slice(span));
+            clone.spans.push(span);
+        }
+        clone
+    }
+}
+
+impl<U, S> Eq for BucketSliceBackend<U, S> where U: Eq {}
+
+impl<U, S> PartialEq for BucketSliceBackend<U, S>
+where
+    U: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.spans.len() == other.spans.len()
+            && self
+                .spans
+                .iter()
+                .zip(&other.spans)
+                .all(|(&lhs, &rhs)| self.span_to_slice(lhs) == other.span_to_slice(rhs))
+    }
+}
+
+impl<'a, U, S> IntoIterator for &'a BucketSliceBackend<U, S>
+where
+    U: Clone,
+    S: Symbol,
+{
+    type Item = (S, &'a [U]);
+    type IntoIter = Iter<'a, U, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, U, S> {
+    backend: &'a BucketSliceBackend<U, S>,
+    iter: Enumerate<slice::Iter<'a, NonNull<[U]>>>,
+}
+
+impl<'a, U, S> Iter<'a, U, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a BucketSliceBackend<U, S>) -> Self {
+        Self {
+            backend,
+            iter: backend.spans.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, U, S> Iterator for Iter<'a, U, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a [U]);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(id, &span)| (expect_valid_symbol(id), self.backend.span_to_slice(span)))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(id, &span)| (expect_valid_symbol(id), self.backend.span_to_slice(span)))
+    }
+}
+
+impl<U, S> ExactSizeIterator for Iter<'_, U, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,318 @@
+use super::GenericBackend;
+use crate::{
+    interner::{get_dedup, get_or_intern_dedup, make_hash},
+    Error,
+};
+use core::{
+    borrow::Borrow,
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::{BuildHasher, Hash},
+    iter::FromIterator,
+    marker::PhantomData,
+};
+use hashbrown::{DefaultHashBuilder, HashMap};
+
+/// Data structure to intern and resolve values of type `T`.
+///
+/// Works like the [`StringInterner`](`crate::StringInterner`) but for any type
+/// `T` that is `?Sized + Hash + Eq`. The interned values are stored in the
+/// [`GenericBackend`] `B`.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::generic::{Interner, OwnedBackend};
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let mut interner = <Interner<Point, OwnedBackend<Point>>>::new();
+/// let origin = interner.get_or_intern(&Point { x: 0, y: 0 });
+/// assert_eq!(interner.get(&Point { x: 0, y: 0 }), Some(origin));
+/// assert_eq!(interner.resolve(origin), Some(&Point { x: 0, y: 0 }));
+/// ```
+pub struct Interner<T, B, H = DefaultHashBuilder>
+where
+    T: ?Sized,
+    B: GenericBackend<T>,
+{
+    dedup: HashMap<<B as GenericBackend<T>>::Symbol, (), ()>,
+    hasher: H,
+    backend: B,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T, B, H> Debug for Interner<T, B, H>
+where
+    T: ?Sized,
+    B: GenericBackend<T> + Debug,
+    <B as GenericBackend<T>>::Symbol: Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interner")
+            .field("dedup", &self.dedup)
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl<T, B, H> Clone for Interner<T, B, H>
+where
+    T: ?Sized,
+    B: GenericBackend<T> + Clone,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            backend: self.backend.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, B, H> PartialEq for Interner<T, B, H>
+where
+    T: ?Sized,
+    B: GenericBackend<T> + PartialEq,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.dedup.len() == rhs.dedup.len() && self.backend == rhs.backend
+    }
+}
+
+impl<T, B, H> Eq for Interner<T, B, H>
+where
+    T: ?Sized,
+    B: GenericBackend<T> + Eq,
+    H: BuildHasher,
+{
+}
+
+impl<T, B, H> Default for Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, B, H> Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `Interner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(H::default())
+    }
+
+    /// Creates a new `Interner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, H::default())
+    }
+}
+
+impl<T, B, H> Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher,
+{
+    /// Creates a new empty `Interner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::default(),
+            hasher: hash_builder,
+            backend: B::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a new empty `Interner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            hasher: hash_builder,
+            backend: B::with_capacity(cap),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of values interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.dedup.len()
+    }
+
+    /// Returns `true` if the interner has no interned values.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given value if any.
+    pub fn get(&self, value: &T) -> Option<<B as GenericBackend<T>>::Symbol> {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let hash = make_hash(hasher, value);
+        get_dedup(dedup, hash, |symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            value == unsafe { backend.resolve_unchecked(symbol) }
+        })
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol for resolution into the original value.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of values possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern(&mut self, value: &T) -> <B as GenericBackend<T>>::Symbol {
+        self.try_get_or_intern(value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol for resolution into the original value.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of values possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    pub fn try_get_or_intern(
+        &mut self,
+        value: &T,
+    ) -> Result<<B as GenericBackend<T>>::Symbol, Error> {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let hash = make_hash(hasher, value);
+        let (symbol, _) = get_or_intern_dedup(
+            dedup,
+            backend,
+            hash,
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                value == unsafe { backend.resolve_unchecked(symbol) }
+            },
+            |backend| backend.try_intern(value).ok_or(Error::OutOfSymbols),
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                let value = unsafe { backend.resolve_unchecked(symbol) };
+                make_hash(hasher, value)
+            },
+        )?;
+        Ok(symbol)
+    }
+
+    /// Shrink backend capacity to fit the interned values exactly.
+    pub fn shrink_to_fit(&mut self) {
+        self.backend.shrink_to_fit()
+    }
+
+    /// Returns the value for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as GenericBackend<T>>::Symbol) -> Option<&T> {
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns the value for the given `symbol` without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`Interner`].
+    #[inline]
+    pub unsafe fn resolve_unchecked(&self, symbol: <B as GenericBackend<T>>::Symbol) -> &T {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.backend.resolve_unchecked(symbol) }
+    }
+
+    /// Returns an iterator that yields all interned values and their symbols.
+    #[inline]
+    pub fn iter(&self) -> <B as GenericBackend<T>>::Iter<'_> {
+        self.backend.iter()
+    }
+}
+
+impl<T, B, H, Q> FromIterator<Q> for Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher + Default,
+    Q: Borrow<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Q>,
+    {
+        let iter = iter.into_iter();
+        let (capacity, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<T, B, H, Q> Extend<Q> for Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher,
+    Q: Borrow<T>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Q>,
+    {
+        for value in iter {
+            self.get_or_intern(value.borrow());
+        }
+    }
+}
+
+impl<'a, T, B, H> IntoIterator for &'a Interner<T, B, H>
+where
+    T: ?Sized + Hash + Eq,
+    B: GenericBackend<T>,
+    H: BuildHasher,
+{
+    type Item = (<B as GenericBackend<T>>::Symbol, &'a T);
+    type IntoIter = <B as GenericBackend<T>>::Iter<'a>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,125 @@
+//! A generic interner for arbitrary `Hash + Eq` values.
+//!
+//! The [`Interner`] works like the [`StringInterner`](`crate::StringInterner`)
+//! but interns values of any type `T` that is `?Sized + Hash + Eq`, such as
+//! `[u32]` slices or small structs. The values are stored by a [`GenericBackend`].
+//!
+//! Every string interner [`Backend`] also is a [`GenericBackend`] for `str`.
+//! Therefore `Interner<str, B>` behaves like `StringInterner<B>` which remains
+//! the specialized interner for strings.
+//!
+//! # Example
+//!
+//! ```
+//! # use string_interner::generic::{Interner, SliceBackend};
+//! let mut interner = <Interner<[u32], SliceBackend<u32>>>::new();
+//! let signature = interner.get_or_intern(&[1, 2, 3]);
+//! assert_eq!(interner.get_or_intern(&[1, 2, 3]), signature);
+//! assert_eq!(interner.resolve(signature), Some(&[1, 2, 3][..]));
+//! ```
+
+mod bucket;
+mod interner;
+mod owned;
+mod slice;
+
+#[doc(inline)]
+pub use self::interner::Interner;
+#[cfg(feature = "backends")]
+#[doc(inline)]
+pub use self::{bucket::BucketSliceBackend, owned::OwnedBackend, slice::SliceBackend};
+use crate::{backend::Backend, Symbol};
+
+/// Types implementing this trait may act as backends for the generic [`Interner`].
+///
+/// This is the counterpart of the string interner [`Backend`] for values of type `T`.
+pub trait GenericBackend<T>: Default
+where
+    T: ?Sized,
+{
+    /// The symbol used by the interner backend.
+    type Symbol: Symbol;
+
+    /// The iterator over the symbols and their values.
+    type Iter<'a>: Iterator<Item = (Self::Symbol, &'a T)>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Creates a new backend for the given capacity.
+    ///
+    /// The capacity denotes how many values are expected to be interned.
+    fn with_capacity(cap: usize) -> Self;
+
+    /// Tries to intern the given value and returns its symbol.
+    ///
+    /// Returns `None` if the backend ran out of symbols in which case
+    /// the backend must remain unchanged.
+    ///
+    /// # Note
+    ///
+    /// The backend must make sure that the returned symbol maps back to the
+    /// original value in its [`resolve`](`GenericBackend::resolve`) method.
+    fn try_intern(&mut self, value: &T) -> Option<Self::Symbol>;
+
+    /// Shrink backend capacity to fit interned symbols exactly.
+    fn shrink_to_fit(&mut self);
+
+    /// Resolves the given symbol to its original value.
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&T>;
+
+    /// Resolves the given symbol to its original value.
+    ///
+    /// # Safety
+    ///
+    /// Does not perform validity checks on the given symbol and relies
+    /// on the caller to be provided with a symbol that has been generated
+    /// by the [`try_intern`](`GenericBackend::try_intern`) method of the
+    /// same interner backend.
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &T;
+
+    /// Creates an iterator that yields all interned values and their symbols.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+impl<B> GenericBackend<str> for B
+where
+    B: Backend,
+{
+    type Symbol = <B as Backend>::Symbol;
+    type Iter<'a>
+        = <B as Backend>::Iter<'a>
+    where
+        Self: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        <B as Backend>::with_capacity(cap)
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        <B as Backend>::try_intern(self, string)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        <B as Backend>::shrink_to_fit(self)
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        <B as Backend>::resolve(self, symbol)
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { <B as Backend>::resolve_unchecked(self, symbol) }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        <B as Backend>::iter(self)
+    }
+}
@@ -0,0 +1,227 @@
+#![cfg(feature = "backends")]
+
+use super::GenericBackend;
+use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt,
+    fmt::{Debug, Formatter},
+    iter::Enumerate,
+    marker::PhantomData,
+    slice,
+};
+
+/// An interner backend that stores every interned value as its owned counterpart.
+///
+/// Interns sized values such as small structs as well as unsized values `T`
+/// via their [`ToOwned::Owned`] type, e.g. `Vec<U>` for `[U]`.
+///
+/// # Usage Hint
+///
+/// Use this backend for sized values or for unsized values for which
+/// there is no specialized backend.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty interner.
+/// - **Resolve:** Efficiency of interned value look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned values.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **best** |
+/// | Allocations | **good** for sized, **bad** for unsized |
+/// | Footprint   | **good** for sized, **ok** for unsized |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **best** |
+pub struct OwnedBackend<T, S = DefaultSymbol>
+where
+    T: ?Sized + ToOwned,
+{
+    values: Vec<<T as ToOwned>::Owned>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<T, S> Debug for OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned,
+    <T as ToOwned>::Owned: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedBackend")
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<T, S> PartialEq for OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(&other.values)
+                .all(|(lhs, rhs)| lhs.borrow() == rhs.borrow())
+    }
+}
+
+impl<T, S> Eq for OwnedBackend<T, S> where T: ?Sized + ToOwned + Eq {}
+
+impl<T, S> Clone for OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned,
+    <T as ToOwned>::Owned: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<T, S> Default for OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            values: Vec::default(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<T, S> GenericBackend<T> for OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned,
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, T, S>
+    where
+        Self: 'a,
+        T: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(cap),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn try_intern(&mut self, value: &T) -> Option<Self::Symbol> {
+        let symbol = S::try_from_usize(self.values.len())?;
+        self.values.push(value.to_owned());
+        Some(symbol)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&T> {
+        self.values.get(symbol.to_usize()).map(Borrow::borrow)
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &T {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.values.get_unchecked(symbol.to_usize()).borrow() }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a OwnedBackend<T, S>
+where
+    T: ?Sized + ToOwned,
+    S: Symbol,
+{
+    type Item = (S, &'a T);
+    type IntoIter = Iter<'a, T, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, T, S>
+where
+    T: ?Sized + ToOwned,
+{
+    iter: Enumerate<slice::Iter<'a, <T as ToOwned>::Owned>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, T, S> Iter<'a, T, S>
+where
+    T: ?Sized + ToOwned,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a OwnedBackend<T, S>) -> Self {
+        Self {
+            iter: backend.values.iter().enumerate(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, T, S> Iterator for Iter<'a, T, S>
+where
+    T: ?Sized + ToOwned + 'a,
+    S: Symbol,
+{
+    type Item = (S, &'a T);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(id, value)| (expect_valid_symbol(id), value.borrow()))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(id, value)| (expect_valid_symbol(id), value.borrow()))
+    }
+}
+
+impl<'a, T, S> ExactSizeIterator for Iter<'a, T, S>
+where
+    T: ?Sized + ToOwned + 'a,
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
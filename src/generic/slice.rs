@@ -0,0 +1,229 @@
+#![cfg(feature = "backends")]
+
+use super::GenericBackend;
+use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    iter::Enumerate,
+    marker::PhantomData,
+    slice,
+};
+
+/// The expected length of interned slices used to estimate the buffer capacity.
+const DEFAULT_SLICE_LEN: usize = 5;
+
+/// An interner backend that accumulates all interned slices into one buffer.
+///
+/// This is the counterpart of the [`StringBackend`](`crate::backend::StringBackend`)
+/// for slices `[U]`.
+///
+/// # Usage Hint
+///
+/// Use this backend if runtime performance is what matters most to you.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty interner.
+/// - **Resolve:** Efficiency of interned slice look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned slices.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **ok**   |
+/// | Allocations | **good** |
+/// | Footprint   | **good** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+pub struct SliceBackend<U, S = DefaultSymbol> {
+    ends: Vec<usize>,
+    buffer: Vec<U>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<U, S> Debug for SliceBackend<U, S>
+where
+    U: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliceBackend")
+            .field("ends", &self.ends)
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+impl<U, S> PartialEq for SliceBackend<U, S>
+where
+    U: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ends == other.ends && self.buffer == other.buffer
+    }
+}
+
+impl<U, S> Eq for SliceBackend<U, S> where U: Eq {}
+
+impl<U, S> Clone for SliceBackend<U, S>
+where
+    U: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ends: self.ends.clone(),
+            buffer: self.buffer.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<U, S> Default for SliceBackend<U, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            ends: Vec::default(),
+            buffer: Vec::default(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<U, S> SliceBackend<U, S>
+where
+    S: Symbol,
+{
+    /// Returns the slice at the given index if any.
+    fn slice_of(&self, index: usize) -> Option<&[U]> {
+        let to = *self.ends.get(index)?;
+        let from = self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0);
+        Some(&self.buffer[from..to])
+    }
+}
+
+impl<U, S> GenericBackend<[U]> for SliceBackend<U, S>
+where
+    U: Clone,
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a>
+        = Iter<'a, U, S>
+    where
+        Self: 'a,
+        U: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            ends: Vec::with_capacity(cap),
+            buffer: Vec::with_capacity(cap * DEFAULT_SLICE_LEN),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn try_intern(&mut self, slice: &[U]) -> Option<Self::Symbol> {
+        let symbol = S::try_from_usize(self.ends.len())?;
+        self.buffer.extend_from_slice(slice);
+        self.ends.push(self.buffer.len());
+        Some(symbol)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.ends.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&[U]> {
+        self.slice_of(symbol.to_usize())
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &[U] {
+        let index = symbol.to_usize();
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe {
+            let to = *self.ends.get_unchecked(index);
+            let from = self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0);
+            self.buffer.get_unchecked(from..to)
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl<'a, U, S> IntoIterator for &'a SliceBackend<U, S>
+where
+    U: Clone,
+    S: Symbol,
+{
+    type Item = (S, &'a [U]);
+    type IntoIter = Iter<'a, U, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, U, S> {
+    buffer: &'a [U],
+    start: usize,
+    ends: Enumerate<slice::Iter<'a, usize>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, U, S> Iter<'a, U, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a SliceBackend<U, S>) -> Self {
+        Self {
+            buffer: &backend.buffer,
+            start: 0,
+            ends: backend.ends.iter().enumerate(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, U, S> Iterator for Iter<'a, U, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a [U]);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ends.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ends.next().map(|(id, &to)| {
+            let from = core::mem::replace(&mut self.start, to);
+            (expect_valid_symbol(id), &self.buffer[from..to])
+        })
+    }
+}
+
+impl<U, S> ExactSizeIterator for Iter<'_, U, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(all(feature = "std", feature = "backends"))]
+
+//! A process-wide string interner.
+//!
+//! The [`GlobalSymbol`] type interns strings into a single interner shared by
+//! all threads of the process. Since interned strings are never removed from it
+//! they can be resolved to `&'static str`.
+//!
+//! # Example
+//!
+//! ```
+//! # use string_interner::global::GlobalSymbol;
+//! let tiger = GlobalSymbol::intern("Tiger");
+//! assert_eq!(GlobalSymbol::intern("Tiger"), tiger);
+//! assert_eq!(GlobalSymbol::get("Tiger"), Some(tiger));
+//! let name: &'static str = tiger.as_str();
+//! assert_eq!(name, "Tiger");
+//! ```
+
+use crate::{ConcurrentStringInterner, DefaultSymbol, Error, Symbol};
+use core::{
+    fmt,
+    fmt::{Debug, Display, Formatter},
+};
+use std::sync::OnceLock;
+
+/// Returns the process-wide interner.
+///
+/// # Note
+///
+/// The [`ConcurrentStringInterner`] is backed by the
+/// [`BucketBackend`](`crate::backend::BucketBackend`) whose stable references
+/// make it sound to hand out its strings as `&'static str`.
+fn interner() -> &'static ConcurrentStringInterner<DefaultSymbol> {
+    static INTERNER: OnceLock<ConcurrentStringInterner<DefaultSymbol>> = OnceLock::new();
+    INTERNER.get_or_init(ConcurrentStringInterner::new)
+}
+
+/// A symbol of the process-wide interner.
+///
+/// Two global symbols are equal if and only if their strings are equal.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GlobalSymbol {
+    symbol: DefaultSymbol,
+}
+
+impl GlobalSymbol {
+    /// Interns the given string into the process-wide interner.
+    ///
+    /// Returns the symbol of the string.
+    ///
+    /// # Panics
+    ///
+    /// If the process-wide interner ran out of symbols.
+    #[inline]
+    pub fn intern<T>(string: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        Self::try_intern(string).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given string into the process-wide interner.
+    ///
+    /// Returns the symbol of the string.
+    ///
+    /// # Errors
+    ///
+    /// If the process-wide interner ran out of symbols.
+    #[inline]
+    pub fn try_intern<T>(string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        interner()
+            .try_get_or_intern(string)
+            .map(|symbol| Self { symbol })
+    }
+
+    /// Returns the symbol for the given string if it has been interned already.
+    #[inline]
+    pub fn get<T>(string: T) -> Option<Self>
+    where
+        T: AsRef<str>,
+    {
+        interner().get(string).map(|symbol| Self { symbol })
+    }
+
+    /// Returns the string of the symbol.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        interner()
+            .resolve(self.symbol)
+            .expect("encountered invalid global symbol")
+    }
+
+    /// Returns the `usize` representation of the symbol.
+    #[inline]
+    pub fn to_usize(self) -> usize {
+        self.symbol.to_usize()
+    }
+}
+
+impl Debug for GlobalSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GlobalSymbol").field(&self.as_str()).finish()
+    }
+}
+
+impl Display for GlobalSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&str> for GlobalSymbol {
+    #[inline]
+    fn from(string: &str) -> Self {
+        Self::intern(string)
+    }
+}
+
+impl AsRef<str> for GlobalSymbol {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
@@ -1,14 +1,24 @@
-use crate::{backend::Backend, Symbol};
+use crate::{
+    backend::{Backend, RemovableBackend, TruncatableBackend},
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    frozen::FrozenInterner,
+    normalize::{Identity, Normalizer},
+    symbol::SymbolMap,
+    Error,
+    Symbol,
+};
+use alloc::vec::Vec;
 use core::{
     fmt,
     fmt::{Debug, Formatter},
     hash::{BuildHasher, Hash, Hasher},
     iter::FromIterator,
+    marker::PhantomData,
 };
 use hashbrown::{DefaultHashBuilder, HashMap};
 
 /// Creates the `u64` hash value for the given value using the given hash builder.
-fn make_hash<T>(builder: &impl BuildHasher, value: &T) -> u64
+pub(crate) fn make_hash<T>(builder: &impl BuildHasher, value: &T) -> u64
 where
     T: ?Sized + Hash,
 {
@@ -17,6 +27,105 @@ where
     state.finish()
 }
 
+/// Creates the `u64` hash value for the given string normalized by `N` using the given hash builder.
+pub(crate) fn make_normalized_hash<N>(builder: &impl BuildHasher, string: &str) -> u64
+where
+    N: Normalizer,
+{
+    let state = &mut builder.build_hasher();
+    N::hash(string, state);
+    state.finish()
+}
+
+/// Returns the symbol with the given `hash` in the `dedup` table for which `eq` returns `true` if any.
+#[inline]
+pub(crate) fn get_dedup<S>(
+    dedup: &HashMap<S, (), ()>,
+    hash: u64,
+    mut eq: impl FnMut(S) -> bool,
+) -> Option<S>
+where
+    S: Copy,
+{
+    dedup
+        .raw_entry()
+        .from_hash(hash, |symbol| eq(*symbol))
+        .map(|(&symbol, &())| symbol)
+}
+
+/// Returns the symbol with the given `hash` in the `dedup` table for which `eq` returns `true`
+/// or otherwise interns a new value into the `backend` via `intern` and inserts its symbol.
+///
+/// Returns the symbol and `true` if a new value has been interned.
+///
+/// - `eq` returns `true` if the value of the symbol within the `backend` is the looked up value.
+/// - `rehash` returns the hash of the value of the symbol within the `backend`.
+///
+/// # Errors
+///
+/// If `intern` fails. In this case the `dedup` table remains unchanged.
+#[cfg_attr(feature = "inline-more", inline)]
+pub(crate) fn get_or_intern_dedup<B, S>(
+    dedup: &mut HashMap<S, (), ()>,
+    backend: &mut B,
+    hash: u64,
+    eq: impl Fn(&B, S) -> bool,
+    intern: impl FnOnce(&mut B) -> Result<S, Error>,
+    rehash: impl Fn(&B, S) -> u64,
+) -> Result<(S, bool), Error>
+where
+    S: Copy,
+{
+    use hashbrown::hash_map::RawEntryMut;
+    let entry = dedup
+        .raw_entry_mut()
+        .from_hash(hash, |symbol| eq(&*backend, *symbol));
+    match entry {
+        RawEntryMut::Occupied(occupied) => Ok((*occupied.into_key(), false)),
+        RawEntryMut::Vacant(vacant) => {
+            let symbol = intern(backend)?;
+            vacant.insert_with_hasher(hash, symbol, (), |symbol| rehash(&*backend, *symbol));
+            Ok((symbol, true))
+        }
+    }
+}
+
+/// Inserts the `symbol` with the given `hash` into the `dedup` table.
+///
+/// The caller has to make sure that the string of `symbol` is not yet part of `dedup`.
+pub(crate) fn insert_dedup<B, H, N>(
+    dedup: &mut HashMap<<B as Backend>::Symbol, (), ()>,
+    hasher: &H,
+    backend: &B,
+    hash: u64,
+    symbol: <B as Backend>::Symbol,
+) where
+    B: Backend,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    use hashbrown::hash_map::RawEntryMut;
+    let RawEntryMut::Vacant(vacant) = dedup.raw_entry_mut().from_hash(hash, |_| false) else {
+        unreachable!("encountered occupied entry for a never matching look-up")
+    };
+    vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        let string = unsafe { backend.resolve_unchecked(*symbol) };
+        make_normalized_hash::<N>(hasher, string)
+    });
+}
+
+/// An opaque marker of the state of a [`StringInterner`].
+///
+/// This is constructed by [`StringInterner::checkpoint`] and consumed by
+/// [`StringInterner::rollback_to`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The number of interned strings at the time of the checkpoint.
+    len: usize,
+}
+
 /// Data structure to intern and resolve strings.
 ///
 /// Caches strings efficiently, with minimal memory footprint and associates them with unique symbols.
@@ -28,16 +137,21 @@ where
 ///     - This maps from `string` type to `symbol` type.
 /// - [`StringInterner::resolve`]: To resolve your already interned strings.
 ///     - This maps from `symbol` type to `string` type.
-pub struct StringInterner<B, H = DefaultHashBuilder>
+///
+/// The [`Normalizer`] `N` defines which strings are interned as the same symbol.
+/// By default only identical strings are. See the [`normalize`](`crate::normalize`)
+/// module for other normalizers.
+pub struct StringInterner<B, H = DefaultHashBuilder, N = Identity>
 where
     B: Backend,
 {
     dedup: HashMap<<B as Backend>::Symbol, (), ()>,
     hasher: H,
     backend: B,
+    normalizer: PhantomData<fn() -> N>,
 }
 
-impl<B, H> Debug for StringInterner<B, H>
+impl<B, H, N> Debug for StringInterner<B, H, N>
 where
     B: Backend + Debug,
     <B as Backend>::Symbol: Symbol + Debug,
@@ -59,7 +173,7 @@ impl Default for StringInterner<crate::DefaultBackend> {
     }
 }
 
-impl<B, H> Clone for StringInterner<B, H>
+impl<B, H, N> Clone for StringInterner<B, H, N>
 where
     B: Backend + Clone,
     <B as Backend>::Symbol: Symbol,
@@ -70,22 +184,23 @@ where
             dedup: self.dedup.clone(),
             hasher: self.hasher.clone(),
             backend: self.backend.clone(),
+            normalizer: PhantomData,
         }
     }
 }
 
-impl<B, H> PartialEq for StringInterner<B, H>
+impl<B, H, N> PartialEq for StringInterner<B, H, N>
 where
     B: Backend + PartialEq,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
 {
     fn eq(&self, rhs: &Self) -> bool {
-        self.len() == rhs.len() && self.backend == rhs.backend
+        self.dedup.len() == rhs.dedup.len() && self.backend == rhs.backend
     }
 }
 
-impl<B, H> Eq for StringInterner<B, H>
+impl<B, H, N> Eq for StringInterner<B, H, N>
 where
     B: Backend + Eq,
     <B as Backend>::Symbol: Symbol,
@@ -93,11 +208,12 @@ where
 {
 }
 
-impl<B, H> StringInterner<B, H>
+impl<B, H, N> StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher + Default,
+    N: Normalizer,
 {
     /// Creates a new empty `StringInterner`.
     #[cfg_attr(feature = "inline-more", inline)]
@@ -106,6 +222,7 @@ where
             dedup: HashMap::default(),
             hasher: Default::default(),
             backend: B::default(),
+            normalizer: PhantomData,
         }
     }
 
@@ -116,15 +233,36 @@ where
             dedup: HashMap::with_capacity_and_hasher(cap, ()),
             hasher: Default::default(),
             backend: B::with_capacity(cap),
+            normalizer: PhantomData,
         }
     }
+
+    /// Creates a new `StringInterner` that interns the strings of the given `backend`.
+    ///
+    /// The symbols of the strings within the backend remain valid for the interner.
+    ///
+    /// # Note
+    ///
+    /// Only the backend is taken over as is. The deduplication table is rebuilt
+    /// which hashes every string of the backend once. Therefore restoring an
+    /// interner from a deserialized backend saves copying its strings one by one
+    /// but not hashing them.
+    ///
+    /// # Errors
+    ///
+    /// If the backend holds the same string more than once.
+    #[inline]
+    pub fn from_backend(backend: B) -> Result<Self, Error> {
+        Self::from_backend_and_hasher(backend, Default::default())
+    }
 }
 
-impl<B, H> StringInterner<B, H>
+impl<B, H, N> StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
+    N: Normalizer,
 {
     /// Creates a new empty `StringInterner` with the given hasher.
     #[cfg_attr(feature = "inline-more", inline)]
@@ -133,6 +271,7 @@ where
             dedup: HashMap::default(),
             hasher: hash_builder,
             backend: B::default(),
+            normalizer: PhantomData,
         }
     }
 
@@ -143,9 +282,71 @@ where
             dedup: HashMap::with_capacity_and_hasher(cap, ()),
             hasher: hash_builder,
             backend: B::with_capacity(cap),
+            normalizer: PhantomData,
         }
     }
 
+    /// Creates a new `StringInterner` that interns the strings of the given `backend`
+    /// using the given hasher.
+    ///
+    /// The symbols of the strings within the backend remain valid for the interner.
+    ///
+    /// # Note
+    ///
+    /// The deduplication table is rebuilt which hashes every string of the backend once.
+    ///
+    /// # Errors
+    ///
+    /// If the backend holds the same string more than once.
+    pub fn from_backend_and_hasher(backend: B, hash_builder: H) -> Result<Self, Error> {
+        use hashbrown::hash_map::RawEntryMut;
+        let hasher = hash_builder;
+        let mut dedup = HashMap::with_capacity_and_hasher(backend.iter().size_hint().0, ());
+        for (symbol, string) in backend.iter() {
+            let hash = make_normalized_hash::<N>(&hasher, string);
+            let entry = dedup.raw_entry_mut().from_hash(hash, |&other| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                N::eq(string, unsafe { backend.resolve_unchecked(other) })
+            });
+            let RawEntryMut::Vacant(vacant) = entry else {
+                return Err(Error::DuplicateString);
+            };
+            vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                let string = unsafe { backend.resolve_unchecked(*symbol) };
+                make_normalized_hash::<N>(&hasher, string)
+            });
+        }
+        Ok(Self {
+            dedup,
+            hasher,
+            backend,
+            normalizer: PhantomData,
+        })
+    }
+
+    /// Returns a shared reference to the backend of the interner.
+    ///
+    /// # Note
+    ///
+    /// Together with [`StringInterner::from_backend`] this allows to serialize
+    /// the raw representation of the backends that support it.
+    #[inline]
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the backend of the interner.
+    ///
+    /// The caller has to make sure that the strings of the symbols in the dedup
+    /// table remain unchanged.
+    #[inline]
+    pub(crate) fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
     /// Returns the number of strings interned by the interner.
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn len(&self) -> usize {
@@ -171,64 +372,118 @@ where
             dedup,
             hasher,
             backend,
+            ..
         } = self;
-        let hash = make_hash(hasher, string);
-        dedup
-            .raw_entry()
-            .from_hash(hash, |symbol| {
-                // SAFETY: This is safe because we only operate on symbols that
-                //         we receive from our backend making them valid.
-                string == unsafe { backend.resolve_unchecked(*symbol) }
-            })
-            .map(|(&symbol, &())| symbol)
+        let hash = make_normalized_hash::<N>(hasher, string);
+        get_dedup(dedup, hash, |symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            N::eq(string, unsafe { backend.resolve_unchecked(symbol) })
+        })
     }
 
-    /// Interns the given string.
+    /// Returns the [`Entry`] for the given string.
     ///
-    /// This is used as backend by [`get_or_intern`][1] and [`get_or_intern_static`][2].
+    /// Allows to query whether a string is already interned and to intern it
+    /// with a single look-up, for example to run side effects only for new strings.
     ///
-    /// [1]: [`StringInterner::get_or_intern`]
-    /// [2]: [`StringInterner::get_or_intern_static`]
-    #[cfg_attr(feature = "inline-more", inline)]
-    fn get_or_intern_using<T>(
-        &mut self,
-        string: T,
-        intern_fn: fn(&mut B, T) -> <B as Backend>::Symbol,
-    ) -> <B as Backend>::Symbol
-    where
-        T: Copy + Hash + AsRef<str> + for<'a> PartialEq<&'a str>,
-    {
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, Entry};
+    /// let mut interner = DefaultStringInterner::default();
+    /// let mut metadata = Vec::new();
+    /// for string in ["a", "b", "a"] {
+    ///     if let Entry::Vacant(vacant) = interner.entry(string) {
+    ///         metadata.push(vacant.key().len());
+    ///         vacant.intern();
+    ///     }
+    /// }
+    /// assert_eq!(interner.len(), 2);
+    /// assert_eq!(metadata.len(), 2);
+    /// ```
+    #[inline]
+    pub fn entry<'a>(&'a mut self, string: &'a str) -> Entry<'a, B, H, N> {
         let Self {
             dedup,
             hasher,
             backend,
+            ..
         } = self;
-        let hash = make_hash(hasher, string.as_ref());
+        let hash = make_normalized_hash::<N>(hasher, string);
         let entry = dedup.raw_entry_mut().from_hash(hash, |symbol| {
             // SAFETY: This is safe because we only operate on symbols that
             //         we receive from our backend making them valid.
-            string == unsafe { backend.resolve_unchecked(*symbol) }
+            N::eq(string, unsafe { backend.resolve_unchecked(*symbol) })
         });
         use hashbrown::hash_map::RawEntryMut;
-        let (&mut symbol, &mut ()) = match entry {
-            RawEntryMut::Occupied(occupied) => occupied.into_key_value(),
+        match entry {
+            RawEntryMut::Occupied(occupied) => {
+                Entry::Occupied(OccupiedEntry::new(*occupied.key(), string))
+            }
             RawEntryMut::Vacant(vacant) => {
-                let symbol = intern_fn(backend, string);
-                vacant.insert_with_hasher(hash, symbol, (), |symbol| {
-                    // SAFETY: This is safe because we only operate on symbols that
-                    //         we receive from our backend making them valid.
-                    let string = unsafe { backend.resolve_unchecked(*symbol) };
-                    make_hash(hasher, string)
-                })
+                Entry::Vacant(VacantEntry::new(vacant, backend, hasher, hash, string))
             }
-        };
-        symbol
+        }
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns the symbol of the string and `true` if the string was newly interned.
+    ///
+    /// This is used as backend by [`try_get_or_intern`][1], [`try_get_or_intern_static`][2]
+    /// and [`get_or_intern_with_status`][3].
+    ///
+    /// [1]: [`StringInterner::try_get_or_intern`]
+    /// [2]: [`StringInterner::try_get_or_intern_static`]
+    /// [3]: [`StringInterner::get_or_intern_with_status`]
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub(crate) fn get_or_intern_using<T>(
+        &mut self,
+        string: T,
+        intern_fn: impl FnOnce(&mut B, T) -> Option<<B as Backend>::Symbol>,
+    ) -> Result<(<B as Backend>::Symbol, bool), Error>
+    where
+        T: Copy + AsRef<str>,
+    {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let hash = make_normalized_hash::<N>(hasher, string.as_ref());
+        get_or_intern_dedup(
+            dedup,
+            backend,
+            hash,
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                N::eq(string.as_ref(), unsafe {
+                    backend.resolve_unchecked(symbol)
+                })
+            },
+            |backend| intern_fn(backend, string).ok_or_else(Error::intern_failed::<B>),
+            |backend, symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                let string = unsafe { backend.resolve_unchecked(symbol) };
+                make_normalized_hash::<N>(hasher, string)
+            },
+        )
     }
 
     /// Interns the given string.
     ///
     /// Returns a symbol for resolution into the original string.
     ///
+    /// # Note
+    ///
+    /// If a string equal to `string` according to the [`Normalizer`] of the
+    /// interner is already interned its symbol is returned and resolves to
+    /// the spelling that has been interned first.
+    ///
     /// # Panics
     ///
     /// If the interner already interns the maximum number of strings possible
@@ -238,7 +493,55 @@ where
     where
         T: AsRef<str>,
     {
-        self.get_or_intern_using(string.as_ref(), B::intern)
+        self.try_get_or_intern(string)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern<T>(&mut self, string: T) -> Result<<B as Backend>::Symbol, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.get_or_intern_using(string.as_ref(), B::try_intern)
+            .map(|(symbol, _)| symbol)
+    }
+
+    /// Interns the given string and reports whether it was newly interned.
+    ///
+    /// Returns a symbol for resolution into the original string as well as
+    /// `true` if the string has not been interned before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = DefaultStringInterner::default();
+    /// let (sym0, is_new0) = interner.get_or_intern_with_status("Tiger");
+    /// let (sym1, is_new1) = interner.get_or_intern_with_status("Tiger");
+    /// assert_eq!(sym0, sym1);
+    /// assert!(is_new0);
+    /// assert!(!is_new1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_with_status<T>(&mut self, string: T) -> (<B as Backend>::Symbol, bool)
+    where
+        T: AsRef<str>,
+    {
+        self.get_or_intern_using(string.as_ref(), B::try_intern)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
     /// Interns the given `'static` string.
@@ -256,7 +559,30 @@ where
     /// by the chosen symbol type.
     #[inline]
     pub fn get_or_intern_static(&mut self, string: &'static str) -> <B as Backend>::Symbol {
-        self.get_or_intern_using(string, B::intern_static)
+        self.try_get_or_intern_static(string)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given `'static` string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// This is more efficient than [`StringInterner::try_get_or_intern`] since it might
+    /// avoid some memory allocations if the backends supports this.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern_static(
+        &mut self,
+        string: &'static str,
+    ) -> Result<<B as Backend>::Symbol, Error> {
+        self.get_or_intern_using(string, B::try_intern_static)
+            .map(|(symbol, _)| symbol)
     }
 
     /// Shrink backend capacity to fit the interned strings exactly.
@@ -264,6 +590,138 @@ where
         self.backend.shrink_to_fit()
     }
 
+    /// Returns the number of strings the interner can hold without reallocating.
+    ///
+    /// # Note
+    ///
+    /// The capacity of some backends is an estimate based on the expected string length.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        usize::min(self.dedup.capacity(), self.backend.capacity())
+    }
+
+    /// Reserves capacity for at least `additional` more strings to be interned.
+    pub fn reserve(&mut self, additional: usize) {
+        self.backend.reserve(additional);
+        if self.dedup.capacity() - self.len() < additional {
+            self.rehash(self.len() + additional);
+        }
+    }
+
+    /// Rebuilds the deduplication table with the given capacity.
+    fn rehash(&mut self, cap: usize) {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let mut rehashed = HashMap::with_capacity_and_hasher(cap, ());
+        for &symbol in dedup.keys() {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            let string = unsafe { backend.resolve_unchecked(symbol) };
+            let hash = make_normalized_hash::<N>(hasher, string);
+            insert_dedup::<_, _, N>(&mut rehashed, hasher, backend, hash, symbol);
+        }
+        *dedup = rehashed;
+    }
+
+    /// Removes all interned strings from the interner.
+    ///
+    /// Keeps the allocated memory for reuse and invalidates all previously returned symbols.
+    pub fn clear(&mut self) {
+        self.dedup.clear();
+        self.backend.clear();
+    }
+
+    /// Rebuilds the interner so that it densely stores all of its interned strings.
+    ///
+    /// Returns a [`SymbolMap`] that maps the old symbols of all interned strings to their new symbols.
+    ///
+    /// # Note
+    ///
+    /// This is especially useful after strings have been removed from the interner,
+    /// for example via [`StringInterner::retain`]. Unlike [`StringInterner::shrink_to_fit`]
+    /// this invalidates all symbols previously handed out by the interner.
+    pub fn compact(&mut self) -> SymbolMap<<B as Backend>::Symbol, <B as Backend>::Symbol> {
+        let len = self.len();
+        let mut dedup = HashMap::with_capacity_and_hasher(len, ());
+        let mut backend = B::with_capacity(len);
+        let mut map = SymbolMap::with_capacity(len);
+        for (old_symbol, string) in self.backend.iter() {
+            let new_symbol = backend.intern(string);
+            let hash = make_normalized_hash::<N>(&self.hasher, string);
+            insert_dedup::<_, _, N>(&mut dedup, &self.hasher, &backend, hash, new_symbol);
+            map.insert(old_symbol, new_symbol);
+        }
+        self.dedup = dedup;
+        self.backend = backend;
+        map
+    }
+
+    /// Converts the interner into a read-only [`FrozenInterner`].
+    ///
+    /// All symbols handed out by the interner remain valid for the frozen interner.
+    #[inline]
+    pub fn freeze(self) -> FrozenInterner<B, H, N> {
+        FrozenInterner::new(self)
+    }
+
+    /// Converts the interner into a read-only [`FrozenInterner`] after moving
+    /// the interned strings into a read-optimized layout and shrinking the
+    /// deduplication table to fit the interned strings exactly.
+    ///
+    /// The layout is determined by [`Backend::pack`], e.g. the
+    /// [`BucketBackend`](`crate::backend::BucketBackend`) moves all of its
+    /// buckets into a single contiguous buffer.
+    ///
+    /// All symbols handed out by the interner remain valid for the frozen interner.
+    pub fn freeze_compact(mut self) -> FrozenInterner<B, H, N> {
+        self.backend.pack();
+        self.rehash(self.len());
+        self.freeze()
+    }
+
+    /// Interns all strings of the `other` interner into this interner.
+    ///
+    /// Returns a [`SymbolMap`] that maps the symbols of `other` to the symbols of
+    /// the same strings in this interner. The interners may use different backends,
+    /// symbol types and hashers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{backend::BufferBackend, DefaultStringInterner, StringInterner};
+    /// let mut interner = DefaultStringInterner::default();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let mut other = <StringInterner<BufferBackend>>::new();
+    /// let horse = other.get_or_intern("Horse");
+    /// let other_tiger = other.get_or_intern("Tiger");
+    /// let map = interner.merge(&other);
+    /// assert_eq!(map.get(other_tiger), Some(tiger));
+    /// assert_eq!(interner.resolve(map.get(horse).unwrap()), Some("Horse"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the interner runs out of symbols while interning the strings of `other`.
+    pub fn merge<B2, H2, N2>(
+        &mut self,
+        other: &StringInterner<B2, H2, N2>,
+    ) -> SymbolMap<<B2 as Backend>::Symbol, <B as Backend>::Symbol>
+    where
+        B2: Backend,
+        H2: BuildHasher,
+        N2: Normalizer,
+    {
+        let mut map = SymbolMap::with_capacity(other.len());
+        for (other_symbol, string) in other {
+            map.insert(other_symbol, self.get_or_intern(string));
+        }
+        map
+    }
+
     /// Returns the string for the given `symbol`` if any.
     #[inline]
     pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
@@ -288,11 +746,152 @@ where
     }
 }
 
-impl<B, H, T> FromIterator<T> for StringInterner<B, H>
+impl<B, H, N> StringInterner<B, H, N>
+where
+    B: RemovableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Removes the string associated to the given `symbol` from the interner.
+    ///
+    /// Returns `true` if a string has been removed and `false` if the symbol
+    /// did not refer to an interned string.
+    ///
+    /// # Note
+    ///
+    /// The backend may hand out the `symbol` again for strings interned afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{backend::FreeListBackend, StringInterner};
+    /// let mut interner = <StringInterner<FreeListBackend>>::new();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// assert!(interner.remove(tiger));
+    /// assert_eq!(interner.get("Tiger"), None);
+    /// assert_eq!(interner.resolve(tiger), None);
+    /// assert!(!interner.remove(tiger));
+    /// ```
+    pub fn remove(&mut self, symbol: <B as Backend>::Symbol) -> bool {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let Some(string) = backend.resolve(symbol) else {
+            return false;
+        };
+        let hash = make_normalized_hash::<N>(hasher, string);
+        use hashbrown::hash_map::RawEntryMut;
+        match dedup
+            .raw_entry_mut()
+            .from_hash(hash, |&other| other == symbol)
+        {
+            RawEntryMut::Occupied(occupied) => occupied.remove(),
+            RawEntryMut::Vacant(_) => return false,
+        };
+        backend.remove(symbol)
+    }
+
+    /// Retains only the strings for which the predicate `f` returns `true`.
+    ///
+    /// The predicate is called once for every interned string and its symbol.
+    /// The symbols of the retained strings remain valid.
+    ///
+    /// # Note
+    ///
+    /// Use [`StringInterner::compact`] afterwards to densely rebuild the interner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{backend::FreeListBackend, StringInterner};
+    /// let mut interner = <StringInterner<FreeListBackend>>::new();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let horse = interner.get_or_intern("Horse");
+    /// interner.retain(|_, string| string != "Tiger");
+    /// assert_eq!(interner.resolve(tiger), None);
+    /// let map = interner.compact();
+    /// assert_eq!(interner.resolve(map.get(horse).unwrap()), Some("Horse"));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(<B as Backend>::Symbol, &str) -> bool,
+    {
+        let removed = self
+            .backend
+            .iter()
+            .filter_map(|(symbol, string)| (!f(symbol, string)).then_some(symbol))
+            .collect::<Vec<_>>();
+        for symbol in removed {
+            self.remove(symbol);
+        }
+    }
+}
+
+impl<B, H, N> StringInterner<B, H, N>
+where
+    B: TruncatableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Returns a [`Checkpoint`] of the current state of the interner.
+    ///
+    /// Use [`StringInterner::rollback_to`] to remove all strings interned afterwards.
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint { len: self.len() }
+    }
+
+    /// Removes all strings that have been interned after the given `checkpoint`.
+    ///
+    /// Only the symbols of the removed strings are invalidated. Checkpoints taken
+    /// after `checkpoint` must not be rolled back to afterwards since they might
+    /// no longer refer to the same state of the interner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = DefaultStringInterner::default();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let checkpoint = interner.checkpoint();
+    /// let horse = interner.get_or_intern("Horse");
+    /// interner.rollback_to(checkpoint);
+    /// assert_eq!(interner.get("Horse"), None);
+    /// assert_eq!(interner.resolve(horse), None);
+    /// assert_eq!(interner.resolve(tiger), Some("Tiger"));
+    /// ```
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        use hashbrown::hash_map::RawEntryMut;
+        for (symbol, string) in backend.iter().skip(checkpoint.len) {
+            let hash = make_normalized_hash::<N>(hasher, string);
+            if let RawEntryMut::Occupied(occupied) = dedup
+                .raw_entry_mut()
+                .from_hash(hash, |&other| other == symbol)
+            {
+                occupied.remove();
+            }
+        }
+        backend.truncate(checkpoint.len);
+    }
+}
+
+impl<B, H, N, T> FromIterator<T> for StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher + Default,
+    N: Normalizer,
     T: AsRef<str>,
 {
     fn from_iter<I>(iter: I) -> Self
@@ -307,11 +906,12 @@ where
     }
 }
 
-impl<B, H, T> Extend<T> for StringInterner<B, H>
+impl<B, H, N, T> Extend<T> for StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
+    N: Normalizer,
     T: AsRef<str>,
 {
     fn extend<I>(&mut self, iter: I)
@@ -324,7 +924,7 @@ where
     }
 }
 
-impl<'a, B, H> IntoIterator for &'a StringInterner<B, H>
+impl<'a, B, H, N> IntoIterator for &'a StringInterner<B, H, N>
 where
     B: Backend,
     H: BuildHasher,
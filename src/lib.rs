@@ -83,19 +83,21 @@
 //! The table below compactly shows when to use which backend according to the following
 //! performance characteristics and properties.
 //!
-//! | **Property** | **BucketBackend** | **StringBackend** | **BufferBackend** | | Explanation |
-//! |:-------------|:-----------------:|:-----------------:|:-----------------:|:--|:--|
-//! | Fill              | 🤷 | 👍 | ⭐ | | Efficiency of filling an empty string interner. |
-//! | Fill Duplicates   | 1) | 1) | 1) | | Efficiency of filling a string interner with strings that are already interned. |
-//! | Resolve           | ⭐ | 👍 | 👎 | | Efficiency of resolving a symbol of an interned string. |
-//! | Resolve Unchecked | 👍 | 👍 | ⭐ 2) | | Efficiency of unchecked resolving a symbol of an interned string. |
-//! | Allocations       | 🤷 | 👍 | ⭐ | | The number of allocations performed by the backend. |
-//! | Footprint         | 🤷 | 👍 | ⭐ | | The total heap memory consumed by the backend. |
-//! | Iteration         | ⭐ | 👍 | 👎 | | Efficiency of iterating over the interned strings. |
-//! |                   | | | | | |
-//! | Contiguous        | ✅ | ✅ | ❌ | | The returned symbols have contiguous values. |
-//! | Stable Refs       | ✅ | ❌ | ❌ | | The interned strings have stable references. |
-//! | Static Strings    | ✅ | ❌ | ❌ | | Allows to intern `&'static str` without heap allocations. |
+//! | **Property** | **BucketBackend** | **StringBackend** | **BufferBackend** | **IndexedBufferBackend** | **FreeListBackend** | **CStrBackend** | | Explanation |
+//! |:-------------|:-----------------:|:-----------------:|:-----------------:|:------------------------:|:-------------------:|:---------------:|:--|:--|
+//! | Fill              | 🤷 | 👍 | ⭐ | 👍 | 🤷 | 👍 | | Efficiency of filling an empty string interner. |
+//! | Fill Duplicates   | 1) | 1) | 1) | 1) | 1) | 1) | | Efficiency of filling a string interner with strings that are already interned. |
+//! | Resolve           | ⭐ | 👍 | 👎 | 👍 | 👍 | 🤷 | | Efficiency of resolving a symbol of an interned string. |
+//! | Resolve Unchecked | 👍 | 👍 | ⭐ 2) | 👍 | 👍 | 👍 | | Efficiency of unchecked resolving a symbol of an interned string. |
+//! | Allocations       | 🤷 | 👍 | ⭐ | 👍 | 👎 | 👍 | | The number of allocations performed by the backend. |
+//! | Footprint         | 🤷 | 👍 | ⭐ | 👍 3) | 🤷 | 👍 | | The total heap memory consumed by the backend. |
+//! | Iteration         | ⭐ | 👍 | 👎 | 👍 | 🤷 | 👍 | | Efficiency of iterating over the interned strings. |
+//! |                   | | | | | | | | |
+//! | Contiguous        | ✅ | ✅ | ❌ | ✅ | ❌ | ✅ | | The returned symbols have contiguous values. |
+//! | Stable Refs       | ✅ | ❌ | ❌ | ❌ | ❌ | ❌ | | The interned strings have stable references. |
+//! | Static Strings    | ✅ | ❌ | ❌ | ❌ | ❌ | ❌ | | Allows to intern `&'static str` without heap allocations. |
+//! | Removal           | ❌ | ❌ | ❌ | ❌ | ✅ | ❌ | | Allows to remove interned strings and reuse their symbols. |
+//! | Rollback          | ✅ | ✅ | ✅ | ✅ | ❌ | ✅ | | Allows to roll back to a checkpoint via [`StringInterner::rollback_to`]. |
 //!
 //! 1. Performance of interning pre-interned string is the same for all backends since
 //!    this is implemented in the `StringInterner` front-end via a `HashMap` query for
@@ -106,6 +108,9 @@
 //!    are performed. If you will only use symbols provided by the same instance of
 //!    `BufferBackend`, `resolve_unchecked` is a lot faster.
 //!
+//! 3. `IndexedBufferBackend` uses the `BufferBackend` layout plus a 32-bit offset per
+//!    interned string which is less than the 64-bit end offsets of the `StringBackend`.
+//!
 //! ### Legend
 //!
 //! | ⭐ | **best performance** | 👍 | **good performance** | 🤷 | **okay performance** | 👎 | **bad performance** |
@@ -130,6 +135,33 @@
 //! However, all this is at the costs of a less efficient resolution of symbols.
 //! Note that the symbols generated by the `BufferBackend` are not contiguous.
 //!
+//! ### IndexedBuffer Backend
+//!
+//! The `IndexedBufferBackend` stores its strings in the same layout as the `BufferBackend`
+//! but additionally keeps an index of their offsets. Therefore its symbols are contiguous
+//! and resolution is efficient at the cost of a slightly bigger memory footprint.
+//!
+//! ### FreeList Backend
+//!
+//! The `FreeListBackend` is the only backend that supports removal of interned strings
+//! via [`StringInterner::remove`]. The symbols of removed strings are reused for newly
+//! interned strings which keeps the memory consumption bounded for long running processes.
+//! However, it performs one allocation per interned string.
+//! The [`RefCountedInterner`] builds upon it to automatically remove strings that are
+//! no longer referenced.
+//!
+//! ### CStr Backend
+//!
+//! The `CStrBackend` stores its strings like the `StringBackend` but with a trailing NUL byte.
+//! This allows to resolve interned strings to [`CStr`](core::ffi::CStr) via
+//! `StringInterner::resolve_cstr` and hand them to FFI without copying.
+//!
+//! ### Mmap Backend
+//!
+//! The `MmapBackend` requires the `mmap` crate feature. It is a read-only backend that
+//! memory maps an interner persisted via [`StringInterner::write_to`] and resolves the
+//! persisted symbols without loading the strings into heap memory.
+//!
 //! ## Customizing String Hashing
 //!
 //! To ensure only one copy of each string is interned, [`StringInterner`] relies on [hashbrown]'s
@@ -163,8 +195,22 @@ extern crate std;
 #[cfg(feature = "serde")]
 mod serde_impl;
 
+mod arena;
 pub mod backend;
+mod borrowed;
+mod bytes;
+mod concurrent;
+mod entry;
+mod error;
+mod frozen;
+pub mod generic;
+pub mod global;
 mod interner;
+pub mod normalize;
+mod path;
+mod persist;
+mod ref_counted;
+mod shared;
 pub mod symbol;
 
 /// A convenience [`StringInterner`] type based on the [`DefaultBackend`].
@@ -172,12 +218,33 @@ pub mod symbol;
 pub type DefaultStringInterner<B = DefaultBackend, H = DefaultHashBuilder> =
     self::interner::StringInterner<B, H>;
 
+#[cfg(all(feature = "std", feature = "backends"))]
+#[doc(inline)]
+pub use self::concurrent::ConcurrentStringInterner;
+#[cfg(all(feature = "std", feature = "backends"))]
+#[doc(inline)]
+pub use self::path::{PathInterner, PathIter};
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use self::serde_impl::WithSymbols;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::shared::{LocalInterner, SharedInterner};
 #[cfg(feature = "backends")]
 #[doc(inline)]
-pub use self::backend::DefaultBackend;
+pub use self::{
+    arena::ArenaInterner,
+    backend::DefaultBackend,
+    bytes::BytesInterner,
+    ref_counted::RefCountedInterner,
+};
 #[doc(inline)]
 pub use self::{
-    interner::StringInterner,
+    borrowed::{BorrowedInterner, BorrowedIter},
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    error::Error,
+    frozen::FrozenInterner,
+    interner::{Checkpoint, StringInterner},
     symbol::{DefaultSymbol, Symbol},
 };
 
@@ -0,0 +1,84 @@
+//! Normalizers that define which strings the [`StringInterner`](`crate::StringInterner`)
+//! treats as equal.
+//!
+//! A [`Normalizer`] is used to hash and compare strings for deduplication.
+//! Strings that are equal under the normalizer are interned as the same symbol
+//! which resolves to the spelling that has been interned first.
+//!
+//! # Example
+//!
+//! ```
+//! # use string_interner::{backend::StringBackend, normalize::AsciiCaseInsensitive, DefaultHashBuilder, StringInterner};
+//! type Interner = StringInterner<StringBackend, DefaultHashBuilder, AsciiCaseInsensitive>;
+//! let mut interner = Interner::new();
+//! let foo = interner.get_or_intern("Foo");
+//! assert_eq!(interner.get_or_intern("FOO"), foo);
+//! assert_eq!(interner.get("foo"), Some(foo));
+//! assert_eq!(interner.resolve(foo), Some("Foo"));
+//! ```
+
+use core::hash::{Hash, Hasher};
+
+/// Types implementing this trait define which strings are equal for an interner.
+///
+/// # Note
+///
+/// Implementations must hash strings that are equal according to
+/// [`Normalizer::eq`] to the same hash value.
+pub trait Normalizer {
+    /// Feeds the normalized form of `string` into the given hasher `state`.
+    fn hash<H>(string: &str, state: &mut H)
+    where
+        H: Hasher;
+
+    /// Returns `true` if `lhs` and `rhs` are equal after normalization.
+    fn eq(lhs: &str, rhs: &str) -> bool;
+}
+
+/// The default normalizer that treats strings as equal only if they are identical.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Identity {}
+
+impl Normalizer for Identity {
+    #[inline]
+    fn hash<H>(string: &str, state: &mut H)
+    where
+        H: Hasher,
+    {
+        string.hash(state)
+    }
+
+    #[inline]
+    fn eq(lhs: &str, rhs: &str) -> bool {
+        lhs == rhs
+    }
+}
+
+/// A normalizer that treats strings as equal if they only differ in ASCII letter case.
+///
+/// Non-ASCII characters are compared exactly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AsciiCaseInsensitive {}
+
+impl Normalizer for AsciiCaseInsensitive {
+    fn hash<H>(string: &str, state: &mut H)
+    where
+        H: Hasher,
+    {
+        // Feeds the lowercase bytes in chunks of a fixed size so that strings
+        // which are equal ignoring ASCII case are hashed identically.
+        let mut buffer = [0x00_u8; 32];
+        for chunk in string.as_bytes().chunks(buffer.len()) {
+            let lowercase = &mut buffer[..chunk.len()];
+            lowercase.copy_from_slice(chunk);
+            lowercase.make_ascii_lowercase();
+            state.write(lowercase);
+        }
+        state.write_u8(0xFF);
+    }
+
+    #[inline]
+    fn eq(lhs: &str, rhs: &str) -> bool {
+        lhs.eq_ignore_ascii_case(rhs)
+    }
+}
@@ -0,0 +1,317 @@
+#![cfg(all(feature = "std", feature = "backends"))]
+
+use crate::{backend::BytesIter, BytesInterner, DefaultSymbol, Error, Symbol};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    iter::FromIterator,
+};
+use hashbrown::DefaultHashBuilder;
+use std::{ffi::OsStr, path::Path};
+
+/// Data structure to intern and resolve paths without lossy conversions.
+///
+/// Paths are interned via the encoded bytes of their [`OsStr`] which are stored
+/// in a [`BytesInterner`]. Therefore paths that are not valid UTF-8 are interned
+/// losslessly and resolve to the exact same [`Path`].
+///
+/// # Note
+///
+/// Paths are deduplicated by their encoded bytes. Therefore paths such as `a/b`
+/// and `a//b` are interned separately even though they compare as equal [`Path`]s.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::PathInterner;
+/// # use std::path::Path;
+/// let mut interner = <PathInterner>::new();
+/// let main = interner.get_or_intern("src/main.rs");
+/// assert_eq!(interner.get_or_intern(Path::new("src/main.rs")), main);
+/// assert_eq!(interner.resolve(main), Some(Path::new("src/main.rs")));
+/// ```
+pub struct PathInterner<S = DefaultSymbol, H = DefaultHashBuilder>
+where
+    S: Symbol,
+{
+    /// Holds the encoded bytes of the interned paths.
+    interner: BytesInterner<S, H>,
+}
+
+impl<S, H> Debug for PathInterner<S, H>
+where
+    S: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<S, H> Clone for PathInterner<S, H>
+where
+    S: Symbol,
+    H: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interner: self.interner.clone(),
+        }
+    }
+}
+
+impl<S, H> PartialEq for PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.interner == rhs.interner
+    }
+}
+
+impl<S, H> Eq for PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S, H> Default for PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `PathInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self {
+            interner: BytesInterner::new(),
+        }
+    }
+
+    /// Creates a new `PathInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            interner: BytesInterner::with_capacity(cap),
+        }
+    }
+}
+
+impl<S, H> PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `PathInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            interner: BytesInterner::with_hasher(hash_builder),
+        }
+    }
+
+    /// Creates a new empty `PathInterner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        Self {
+            interner: BytesInterner::with_capacity_and_hasher(cap, hash_builder),
+        }
+    }
+
+    /// Returns the number of paths interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    /// Returns `true` if the interner has no interned paths.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Returns the symbol for the given path if any.
+    #[inline]
+    pub fn get<T>(&self, path: T) -> Option<S>
+    where
+        T: AsRef<Path>,
+    {
+        self.interner
+            .get(path.as_ref().as_os_str().as_encoded_bytes())
+    }
+
+    /// Interns the given path.
+    ///
+    /// Returns a symbol for resolution into the original path.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of paths possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, path: T) -> S
+    where
+        T: AsRef<Path>,
+    {
+        self.try_get_or_intern(path)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given path.
+    ///
+    /// Returns a symbol for resolution into the original path.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of paths possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern<T>(&mut self, path: T) -> Result<S, Error>
+    where
+        T: AsRef<Path>,
+    {
+        self.interner
+            .try_get_or_intern(path.as_ref().as_os_str().as_encoded_bytes())
+    }
+
+    /// Shrink backend capacity to fit the interned paths exactly.
+    pub fn shrink_to_fit(&mut self) {
+        self.interner.shrink_to_fit()
+    }
+
+    /// Returns the path for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&Path> {
+        let bytes = self.interner.resolve(symbol)?;
+        // Symbols that do not belong to the interner may resolve to bytes in the
+        // middle of an interned path which need not be a valid encoding.
+        #[cfg(not(unix))]
+        if self.interner.get(bytes) != Some(symbol) {
+            return None;
+        }
+        // SAFETY: On non-unix platforms we checked above that `bytes` are the
+        //         entire encoded bytes of the interned path of `symbol`.
+        Some(unsafe { bytes_to_path(bytes) })
+    }
+
+    /// Returns an iterator that yields all interned paths and their symbols.
+    #[inline]
+    pub fn iter(&self) -> PathIter<'_, S> {
+        PathIter {
+            bytes: self.interner.iter(),
+        }
+    }
+}
+
+/// Converts the encoded bytes of an interned path back into the path.
+///
+/// # Safety
+///
+/// On non-unix platforms `bytes` must be the entire encoded bytes of an interned path.
+#[inline]
+unsafe fn bytes_to_path(bytes: &[u8]) -> &Path {
+    #[cfg(unix)]
+    let os_str = <OsStr as std::os::unix::ffi::OsStrExt>::from_bytes(bytes);
+    // SAFETY: The caller guarantees that `bytes` have been returned by
+    //         `OsStr::as_encoded_bytes` within the same process.
+    #[cfg(not(unix))]
+    let os_str = unsafe { OsStr::from_encoded_bytes_unchecked(bytes) };
+    Path::new(os_str)
+}
+
+impl<S, H, T> FromIterator<T> for PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+    T: AsRef<Path>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let (capacity, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<S, H, T> Extend<T> for PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+    T: AsRef<Path>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for path in iter {
+            self.get_or_intern(path);
+        }
+    }
+}
+
+impl<'a, S, H> IntoIterator for &'a PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Item = (S, &'a Path);
+    type IntoIter = PathIter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the paths of a [`PathInterner`] and their symbols.
+pub struct PathIter<'a, S> {
+    bytes: BytesIter<'a, S>,
+}
+
+impl<'a, S> Iterator for PathIter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a Path);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bytes
+            .next()
+            // SAFETY: The iterator only yields the entire bytes of interned paths.
+            .map(|(symbol, bytes)| (symbol, unsafe { bytes_to_path(bytes) }))
+    }
+}
+
+impl<S> ExactSizeIterator for PathIter<'_, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+}
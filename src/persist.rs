@@ -0,0 +1,371 @@
+#![cfg(all(feature = "std", feature = "backends"))]
+
+use crate::{
+    backend::{decode_var_usize, encode_var_usize, Backend},
+    normalize::Normalizer,
+    StringInterner,
+    Symbol,
+};
+use alloc::vec::Vec;
+use core::{hash::BuildHasher, mem, str};
+use std::io::{self, Read};
+
+/// The magic bytes that start every persisted interner.
+const MAGIC: [u8; 4] = *b"STRI";
+
+/// The version of the format written by [`StringInterner::write_to`].
+const VERSION: u8 = 1;
+
+/// The maximum number of bytes of a `var7` encoded `usize`.
+const MAX_VAR_USIZE_BYTES: usize = (usize::BITS as usize).div_ceil(7);
+
+/// Computes the 64-bit FNV-1a hash of all bytes written or read.
+struct Checksum(u64);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+}
+
+/// The header of a persisted interner.
+pub(crate) struct Header {
+    /// The [`Backend::KIND`] of the persisted interner.
+    pub kind: Vec<u8>,
+    /// The size of the symbol type of the persisted interner in bytes.
+    pub symbol_width: u8,
+    /// The number of persisted strings.
+    pub len_strings: usize,
+    /// The length of the body in bytes.
+    pub body_len: usize,
+}
+
+/// Reads from the underlying reader while updating the checksum.
+struct Reader<R> {
+    reader: R,
+    checksum: Checksum,
+}
+
+impl<R> Reader<R>
+where
+    R: io::Read,
+{
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            checksum: Checksum::new(),
+        }
+    }
+
+    fn read_header(&mut self) -> io::Result<Header> {
+        let mut magic = [0x00; MAGIC.len()];
+        self.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("missing magic bytes"));
+        }
+        if self.read_u8()? != VERSION {
+            return Err(invalid_data("unsupported format version"));
+        }
+        let kind_len = self.read_var_usize()?;
+        let kind = self.read_vec(kind_len)?;
+        let symbol_width = self.read_u8()?;
+        let len_strings = self.read_var_usize()?;
+        let body_len = self.read_var_usize()?;
+        Ok(Header {
+            kind,
+            symbol_width,
+            len_strings,
+            body_len,
+        })
+    }
+
+    /// Reads the checksum and compares it to the checksum of all bytes read so far.
+    fn read_checksum(mut self) -> io::Result<()> {
+        let mut checksum = [0x00; 8];
+        self.reader.read_exact(&mut checksum)?;
+        if u64::from_le_bytes(checksum) != self.checksum.0 {
+            return Err(invalid_data("mismatching checksum"));
+        }
+        Ok(())
+    }
+
+    fn read_exact(&mut self, buffer: &mut [u8]) -> io::Result<()> {
+        self.reader.read_exact(buffer)?;
+        self.checksum.update(buffer);
+        Ok(())
+    }
+
+    fn read_u8(&mut self) -> io::Result<u8> {
+        let mut byte = [0x00];
+        self.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+
+    fn read_var_usize(&mut self) -> io::Result<usize> {
+        let mut bytes = [0x00; MAX_VAR_USIZE_BYTES];
+        for len in 1..=MAX_VAR_USIZE_BYTES {
+            let byte = self.read_u8()?;
+            bytes[len - 1] = byte;
+            if byte & 0x80 == 0 {
+                return decode_var_usize(&bytes[..len])
+                    .map(|(value, _)| value)
+                    .ok_or_else(|| invalid_data("invalid length encoding"));
+            }
+        }
+        Err(invalid_data("invalid length encoding"))
+    }
+
+    /// Reads exactly `len` bytes without trusting `len` for the initial allocation.
+    fn read_vec(&mut self, len: usize) -> io::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        (&mut self.reader)
+            .take(len as u64)
+            .read_to_end(&mut buffer)?;
+        if buffer.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        self.checksum.update(&buffer);
+        Ok(buffer)
+    }
+}
+
+/// Splits the complete persisted interner `bytes` into its header and its body.
+///
+/// # Errors
+///
+/// If the header is malformed, the checksum does not match or if there
+/// are trailing bytes.
+#[cfg(feature = "mmap")]
+pub(crate) fn split_persisted(bytes: &[u8]) -> io::Result<(Header, &[u8])> {
+    let mut reader = Reader::new(bytes);
+    let header = reader.read_header()?;
+    let (body, checksum) = reader
+        .reader
+        .split_at_checked(header.body_len)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+    if checksum.len() != mem::size_of::<u64>() {
+        return Err(invalid_data("invalid length of the checksum"));
+    }
+    reader.checksum.update(body);
+    reader.reader = checksum;
+    reader.read_checksum()?;
+    Ok((header, body))
+}
+
+/// Decodes the entry at the start of the given `body`.
+///
+/// Returns the symbol and the string of the entry and the length of the entry in bytes.
+///
+/// # Errors
+///
+/// If the entry is malformed.
+pub(crate) fn decode_entry(body: &[u8]) -> io::Result<(usize, &str, usize)> {
+    let mut offset = 0;
+    let mut read_var_usize = || {
+        let (value, len) = decode_var_usize(&body[offset..])
+            .ok_or_else(|| invalid_data("invalid length encoding"))?;
+        offset += len;
+        io::Result::Ok(value)
+    };
+    let symbol = read_var_usize()?;
+    let str_len = read_var_usize()?;
+    let str_bytes = offset
+        .checked_add(str_len)
+        .and_then(|end| body.get(offset..end))
+        .ok_or_else(|| invalid_data("string length exceeds the body"))?;
+    let string =
+        str::from_utf8(str_bytes).map_err(|_| invalid_data("invalid UTF-8 encoded string"))?;
+    Ok((symbol, string, offset + str_len))
+}
+
+/// Creates an [`io::Error`] for malformed input.
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Returns the size of the symbol type `S` in bytes.
+/// Interns the `string` into the `backend` and returns its symbol.
+///
+/// If the backend supports removal, the symbols below the given `symbol` that are
+/// handed out by the backend instead are occupied by placeholders which are pushed
+/// to `placeholders`. This restores the symbols of strings that have been removed
+/// before the interner has been written.
+fn restore_symbol<B>(
+    backend: &mut B,
+    string: &str,
+    symbol: usize,
+    placeholders: &mut Vec<<B as Backend>::Symbol>,
+) -> Option<<B as Backend>::Symbol>
+where
+    B: Backend,
+{
+    loop {
+        let restored = backend.try_intern(string)?;
+        if restored.to_usize() >= symbol || !backend.try_remove(restored) {
+            return Some(restored);
+        }
+        // Removing the string made its symbol the only free symbol of the backend
+        // so that the placeholder is interned with it.
+        let placeholder = backend.try_intern("")?;
+        debug_assert!(placeholder == restored);
+        placeholders.push(placeholder);
+    }
+}
+
+fn symbol_width<S>() -> u8
+where
+    S: Symbol,
+{
+    mem::size_of::<S>() as u8
+}
+
+impl<B, H, N> StringInterner<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    /// Writes the interner to the given writer in a compact binary format.
+    ///
+    /// The format records the kind of the backend, the width of the symbols as
+    /// well as the symbols of all interned strings and is protected by a checksum.
+    /// Use [`StringInterner::read_from`] to restore the interner.
+    ///
+    /// # Format
+    ///
+    /// All lengths and symbols are `var7` encoded as in the
+    /// [`BufferBackend`](`crate::backend::BufferBackend`).
+    ///
+    /// | Field          | Encoding |
+    /// |:---------------|:---------|
+    /// | Magic          | the bytes `b"STRI"` |
+    /// | Version        | `u8` |
+    /// | Backend kind   | length prefixed [`Backend::KIND`] |
+    /// | Symbol width   | `u8` size of the symbol type in bytes |
+    /// | Strings        | number of interned strings |
+    /// | Body length    | length of the body in bytes |
+    /// | Body           | symbol and length prefixed string per interned string |
+    /// | Checksum       | little-endian `u64` FNV-1a hash of all previous bytes |
+    ///
+    /// # Errors
+    ///
+    /// If writing to the writer fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let mut snapshot = Vec::new();
+    /// interner.write_to(&mut snapshot).unwrap();
+    /// let restored = <DefaultStringInterner>::read_from(&snapshot[..]).unwrap();
+    /// assert_eq!(restored.resolve(tiger), Some("Tiger"));
+    /// ```
+    pub fn write_to<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let mut body = Vec::new();
+        for (symbol, string) in self.iter() {
+            encode_var_usize(&mut body, symbol.to_usize());
+            encode_var_usize(&mut body, string.len());
+            body.extend_from_slice(string.as_bytes());
+        }
+        let mut header = Vec::new();
+        header.extend_from_slice(&MAGIC);
+        header.push(VERSION);
+        encode_var_usize(&mut header, B::KIND.len());
+        header.extend_from_slice(B::KIND.as_bytes());
+        header.push(symbol_width::<<B as Backend>::Symbol>());
+        encode_var_usize(&mut header, self.len());
+        encode_var_usize(&mut header, body.len());
+        let mut checksum = Checksum::new();
+        checksum.update(&header);
+        checksum.update(&body);
+        writer.write_all(&header)?;
+        writer.write_all(&body)?;
+        writer.write_all(&checksum.0.to_le_bytes())
+    }
+}
+
+impl<B, H, N> StringInterner<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+    N: Normalizer,
+{
+    /// Reads an interner from the given reader that has been written by
+    /// [`StringInterner::write_to`].
+    ///
+    /// All strings are restored with the symbols they had when written.
+    /// For backends that support removal the symbols of strings removed before
+    /// writing are free again and reused for subsequently interned strings.
+    ///
+    /// # Note
+    ///
+    /// Small parts of the format are read byte-wise so that nothing is read
+    /// past the written interner. Wrap unbuffered readers into an
+    /// [`io::BufReader`] for efficiency.
+    ///
+    /// # Errors
+    ///
+    /// - If reading from the reader fails.
+    /// - If the input is malformed or its checksum does not match.
+    /// - If the input has been written by an interner with a different kind
+    ///   of backend or a different symbol type.
+    /// - If the symbols of the input cannot be restored by the backend.
+    /// - If the input holds strings that are equal according to the normalizer `N`.
+    pub fn read_from<R>(reader: R) -> io::Result<Self>
+    where
+        R: io::Read,
+    {
+        let mut reader = Reader::new(reader);
+        let header = reader.read_header()?;
+        if header.kind != B::KIND.as_bytes() {
+            return Err(invalid_data("mismatching backend kind"));
+        }
+        if header.symbol_width != symbol_width::<<B as Backend>::Symbol>() {
+            return Err(invalid_data("mismatching symbol width"));
+        }
+        let body = reader.read_vec(header.body_len)?;
+        reader.read_checksum()?;
+        // Every entry takes at least two bytes so that a malformed header
+        // cannot make us reserve more than what the body can hold.
+        let mut interner = Self::with_capacity(usize::min(header.len_strings, body.len() / 2));
+        // The symbols of removed strings are occupied by placeholders until all strings are restored.
+        let mut placeholders = Vec::new();
+        let mut rest = &body[..];
+        while !rest.is_empty() {
+            let (symbol, string, len) = decode_entry(rest)?;
+            rest = &rest[len..];
+            let (restored, is_new) = interner
+                .get_or_intern_using(string, |backend, string| {
+                    restore_symbol(backend, string, symbol, &mut placeholders)
+                })
+                .map_err(|_| invalid_data("too many strings for the symbol type"))?;
+            if !is_new {
+                return Err(invalid_data("duplicate string"));
+            }
+            if restored.to_usize() != symbol {
+                return Err(invalid_data("symbol cannot be restored by the backend"));
+            }
+        }
+        for placeholder in placeholders {
+            interner.backend_mut().try_remove(placeholder);
+        }
+        if interner.len() != header.len_strings {
+            return Err(invalid_data("mismatching number of strings"));
+        }
+        Ok(interner)
+    }
+}
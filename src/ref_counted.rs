@@ -0,0 +1,247 @@
+#![cfg(feature = "backends")]
+
+use crate::{
+    backend::{Backend, FreeListBackend, RemovableBackend},
+    DefaultHashBuilder,
+    Error,
+    StringInterner,
+    Symbol,
+};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+};
+
+/// A string interner that reference counts its interned strings.
+///
+/// Every call to [`get_or_intern`](`RefCountedInterner::get_or_intern`) increments
+/// the reference count of the string and every call to
+/// [`release`](`RefCountedInterner::release`) decrements it. Strings are removed from
+/// the interner once their reference count drops to zero.
+///
+/// Since removed strings are also removed from the deduplication table, interning a
+/// released string again yields a valid, but possibly different symbol.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::RefCountedInterner;
+/// let mut interner = <RefCountedInterner>::new();
+/// let tiger = interner.get_or_intern("Tiger");
+/// assert_eq!(interner.get_or_intern("Tiger"), tiger);
+/// assert_eq!(interner.ref_count(tiger), 2);
+/// assert_eq!(interner.release(tiger), Some(1));
+/// assert_eq!(interner.release(tiger), Some(0));
+/// assert_eq!(interner.resolve(tiger), None);
+/// assert!(interner.is_empty());
+/// ```
+pub struct RefCountedInterner<B = FreeListBackend, H = DefaultHashBuilder>
+where
+    B: RemovableBackend,
+{
+    interner: StringInterner<B, H>,
+    /// The reference counts of the interned strings indexed by their symbols.
+    counts: Vec<usize>,
+}
+
+impl<B, H> Debug for RefCountedInterner<B, H>
+where
+    B: RemovableBackend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefCountedInterner")
+            .field("interner", &self.interner)
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+impl<B, H> Default for RefCountedInterner<B, H>
+where
+    B: RemovableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, H> Clone for RefCountedInterner<B, H>
+where
+    B: RemovableBackend + Clone,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interner: self.interner.clone(),
+            counts: self.counts.clone(),
+        }
+    }
+}
+
+impl<B, H> RefCountedInterner<B, H>
+where
+    B: RemovableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `RefCountedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self {
+            interner: StringInterner::new(),
+            counts: Vec::new(),
+        }
+    }
+
+    /// Creates a new `RefCountedInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            interner: StringInterner::with_capacity(cap),
+            counts: Vec::with_capacity(cap),
+        }
+    }
+}
+
+impl<B, H> RefCountedInterner<B, H>
+where
+    B: RemovableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `RefCountedInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            interner: StringInterner::with_hasher(hash_builder),
+            counts: Vec::new(),
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    /// Returns `true` if the interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Returns the symbol for the given string if any.
+    ///
+    /// Does not change the reference count of the string.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.get(string)
+    }
+
+    /// Interns the given string and increments its reference count.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+    {
+        self.try_get_or_intern(string)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Interns the given string and increments its reference count.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern<T>(&mut self, string: T) -> Result<<B as Backend>::Symbol, Error>
+    where
+        T: AsRef<str>,
+    {
+        let symbol = self.interner.try_get_or_intern(string)?;
+        let index = symbol.to_usize();
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+        Ok(symbol)
+    }
+
+    /// Returns the reference count of the string associated to the given `symbol`.
+    ///
+    /// Returns zero if the symbol does not refer to an interned string.
+    #[inline]
+    pub fn ref_count(&self, symbol: <B as Backend>::Symbol) -> usize {
+        self.counts.get(symbol.to_usize()).copied().unwrap_or(0)
+    }
+
+    /// Decrements the reference count of the string associated to the given `symbol`.
+    ///
+    /// Removes the string from the interner once its reference count drops to zero.
+    ///
+    /// Returns the remaining reference count or `None` if the symbol did not
+    /// refer to an interned string.
+    pub fn release(&mut self, symbol: <B as Backend>::Symbol) -> Option<usize> {
+        let count = self
+            .counts
+            .get_mut(symbol.to_usize())
+            .filter(|count| **count > 0)?;
+        *count -= 1;
+        let count = *count;
+        if count == 0 {
+            let removed = self.interner.remove(symbol);
+            debug_assert!(removed, "encountered reference count for a removed string");
+        }
+        Some(count)
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    ///
+    /// Strings that have been released entirely are skipped.
+    #[inline]
+    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
+        self.interner.iter()
+    }
+}
+
+impl<'a, B, H> IntoIterator for &'a RefCountedInterner<B, H>
+where
+    B: RemovableBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+    type IntoIter = <B as Backend>::Iter<'a>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
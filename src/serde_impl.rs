@@ -1,17 +1,22 @@
-use crate::{backend::Backend, StringInterner, Symbol};
+#[cfg(feature = "backends")]
+use crate::backend::{BucketBackend, BufferBackend, IndexedBufferBackend, StringBackend};
+use crate::{backend::Backend, normalize::Normalizer, BorrowedInterner, StringInterner, Symbol};
 use alloc::boxed::Box;
+#[cfg(feature = "backends")]
+use alloc::{string::String, vec::Vec};
 use core::{default::Default, fmt, hash::BuildHasher, marker};
 use serde::{
-    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
     ser::{Serialize, SerializeSeq, Serializer},
 };
 
-impl<B, H> Serialize for StringInterner<B, H>
+impl<B, H, N> Serialize for StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     for<'a> &'a B: IntoIterator<Item = (<B as Backend>::Symbol, &'a str)>,
     H: BuildHasher,
+    N: Normalizer,
 {
     fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
     where
@@ -25,13 +30,14 @@ where
     }
 }
 
-impl<'de, B, H> Deserialize<'de> for StringInterner<B, H>
+impl<'de, B, H, N> Deserialize<'de> for StringInterner<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher + Default,
+    N: Normalizer,
 {
-    fn deserialize<D>(deserializer: D) -> Result<StringInterner<B, H>, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<StringInterner<B, H, N>, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -39,20 +45,22 @@ where
     }
 }
 
-struct StringInternerVisitor<B, H>
+struct StringInternerVisitor<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
+    N: Normalizer,
 {
-    mark: marker::PhantomData<(<B as Backend>::Symbol, B, H)>,
+    mark: marker::PhantomData<(<B as Backend>::Symbol, B, H, N)>,
 }
 
-impl<B, H> Default for StringInternerVisitor<B, H>
+impl<B, H, N> Default for StringInternerVisitor<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
+    N: Normalizer,
 {
     fn default() -> Self {
         StringInternerVisitor {
@@ -61,13 +69,14 @@ where
     }
 }
 
-impl<'de, B, H> Visitor<'de> for StringInternerVisitor<B, H>
+impl<'de, B, H, N> Visitor<'de> for StringInternerVisitor<B, H, N>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher + Default,
+    N: Normalizer,
 {
-    type Value = StringInterner<B, H>;
+    type Value = StringInterner<B, H, N>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("Expected a contiguous sequence of strings.")
@@ -77,7 +86,7 @@ where
     where
         A: SeqAccess<'de>,
     {
-        let mut interner: StringInterner<B, H> =
+        let mut interner: StringInterner<B, H, N> =
             StringInterner::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), H::default());
         while let Some(s) = seq.next_element::<Box<str>>()? {
             interner.get_or_intern(s);
@@ -86,6 +95,413 @@ where
     }
 }
 
+impl<S, H> Serialize for BorrowedInterner<'_, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for (_symbol, string) in self {
+            seq.serialize_element(string)?
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes the [`BorrowedInterner`] by borrowing its strings from the input.
+///
+/// # Note
+///
+/// This fails for inputs or formats that do not allow borrowing the strings,
+/// for example JSON strings with escape sequences.
+impl<'de, S, H> Deserialize<'de> for BorrowedInterner<'de, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<BorrowedInterner<'de, S, H>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BorrowedInternerVisitor::default())
+    }
+}
+
+struct BorrowedInternerVisitor<S, H> {
+    mark: marker::PhantomData<(S, H)>,
+}
+
+impl<S, H> Default for BorrowedInternerVisitor<S, H> {
+    fn default() -> Self {
+        BorrowedInternerVisitor {
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, S, H> Visitor<'de> for BorrowedInternerVisitor<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    type Value = BorrowedInterner<'de, S, H>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Expected a contiguous sequence of borrowed strings.")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut interner: BorrowedInterner<'de, S, H> =
+            BorrowedInterner::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), H::default());
+        while let Some(s) = seq.next_element::<&'de str>()? {
+            interner.try_get_or_intern(s).map_err(de::Error::custom)?;
+        }
+        Ok(interner)
+    }
+}
+
+/// Serializes a [`StringInterner`] together with the symbols of its strings.
+///
+/// The default serialization of [`StringInterner`] only writes its strings, so that
+/// deserializing it only reproduces the original symbols for backends with contiguous
+/// symbols. Instead this wrapper writes a sequence of `(symbol, string)` pairs and
+/// verifies upon deserialization that every string is assigned exactly the symbol
+/// it has been serialized with.
+///
+/// Deserialization fails for symbols that are out of range for the symbol type
+/// and for inputs with gaps between symbols or duplicate symbols or strings.
+/// Strings that are equal according to the [`Normalizer`] of the interner count as duplicates.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::{backend::BufferBackend, StringInterner, WithSymbols};
+/// let mut interner = <StringInterner<BufferBackend>>::new();
+/// let tiger = interner.get_or_intern("Tiger");
+/// let horse = interner.get_or_intern("Horse");
+/// let json = serde_json::to_string(&WithSymbols(&interner)).unwrap();
+/// let WithSymbols(deserialized): WithSymbols<StringInterner<BufferBackend>> =
+///     serde_json::from_str(&json).unwrap();
+/// assert_eq!(deserialized.resolve(tiger), Some("Tiger"));
+/// assert_eq!(deserialized.resolve(horse), Some("Horse"));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WithSymbols<T>(pub T);
+
+impl<T> WithSymbols<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<B, H, N> Serialize for WithSymbols<&StringInterner<B, H, N>>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for (symbol, string) in self.0 {
+            seq.serialize_element(&(symbol.to_usize(), string))?
+        }
+        seq.end()
+    }
+}
+
+impl<B, H, N> Serialize for WithSymbols<StringInterner<B, H, N>>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        WithSymbols(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de, B, H, N> Deserialize<'de> for WithSymbols<StringInterner<B, H, N>>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+    N: Normalizer,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_seq(WithSymbolsVisitor::default())
+            .map(WithSymbols)
+    }
+}
+
+struct WithSymbolsVisitor<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    mark: marker::PhantomData<(<B as Backend>::Symbol, B, H, N)>,
+}
+
+impl<B, H, N> Default for WithSymbolsVisitor<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    N: Normalizer,
+{
+    fn default() -> Self {
+        WithSymbolsVisitor {
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, B, H, N> Visitor<'de> for WithSymbolsVisitor<B, H, N>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+    N: Normalizer,
+{
+    type Value = StringInterner<B, H, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Expected a sequence of symbol and string pairs.")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut interner: StringInterner<B, H, N> =
+            StringInterner::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), H::default());
+        while let Some((expected, string)) = seq.next_element::<(usize, Box<str>)>()? {
+            let (symbol, is_new) = interner
+                .get_or_intern_using(&*string, B::try_intern)
+                .map_err(de::Error::custom)?;
+            if !is_new {
+                return Err(de::Error::custom(format_args!(
+                    "duplicate string for symbol {expected}"
+                )));
+            }
+            if symbol.to_usize() != expected {
+                return Err(de::Error::custom(format_args!(
+                    "symbol {expected} does not match the restored symbol {}",
+                    symbol.to_usize()
+                )));
+            }
+        }
+        Ok(interner)
+    }
+}
+
+/// Serializes a byte slice as a single byte blob.
+#[cfg(feature = "backends")]
+struct Bytes<'a>(&'a [u8]);
+
+#[cfg(feature = "backends")]
+impl Serialize for Bytes<'_> {
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Deserializes a byte blob or a sequence of bytes.
+#[cfg(feature = "backends")]
+struct ByteBuf(Vec<u8>);
+
+#[cfg(feature = "backends")]
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+#[cfg(feature = "backends")]
+struct ByteBufVisitor;
+
+#[cfg(feature = "backends")]
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = ByteBuf;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Expected a byte buffer.")
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ByteBuf(bytes.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ByteBuf(bytes))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(ByteBuf(bytes))
+    }
+}
+
+/// Serializes the [`StringBackend`] as the tuple of its string end offsets and its buffer.
+///
+/// # Note
+///
+/// Deserializing a backend validates and takes over its raw layout as is.
+/// Restoring a [`StringInterner`] from it via [`StringInterner::from_backend`]
+/// still hashes every string once in order to rebuild the deduplication table.
+#[cfg(feature = "backends")]
+impl<S> Serialize for StringBackend<S>
+where
+    S: Symbol,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        self.as_raw_parts().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "backends")]
+impl<'de, S> Deserialize<'de> for StringBackend<S>
+where
+    S: Symbol,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (ends, buffer) = <(Vec<usize>, String)>::deserialize(deserializer)?;
+        Self::from_raw_parts(ends, buffer).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the [`BucketBackend`] as the tuple of its string end offsets and
+/// the concatenation of its strings.
+#[cfg(feature = "backends")]
+impl<S> Serialize for BucketBackend<S>
+where
+    S: Symbol,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        self.to_raw_parts().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "backends")]
+impl<'de, S> Deserialize<'de> for BucketBackend<S>
+where
+    S: Symbol,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (ends, buffer) = <(Vec<usize>, String)>::deserialize(deserializer)?;
+        Self::from_raw_parts(ends, buffer).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the [`BufferBackend`] as the tuple of its number of strings and its buffer.
+#[cfg(feature = "backends")]
+impl<S> Serialize for BufferBackend<S>
+where
+    S: Symbol,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        let (len_strings, buffer) = self.as_raw_parts();
+        (len_strings, Bytes(buffer)).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "backends")]
+impl<'de, S> Deserialize<'de> for BufferBackend<S>
+where
+    S: Symbol,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (len_strings, ByteBuf(buffer)) = <(usize, ByteBuf)>::deserialize(deserializer)?;
+        Self::from_raw_parts(len_strings, buffer).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the [`IndexedBufferBackend`] as its buffer.
+#[cfg(feature = "backends")]
+impl<S> Serialize for IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        Bytes(self.as_raw_parts()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "backends")]
+impl<'de, S> Deserialize<'de> for IndexedBufferBackend<S>
+where
+    S: Symbol,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ByteBuf(buffer) = ByteBuf::deserialize(deserializer)?;
+        Self::from_raw_parts(buffer).map_err(de::Error::custom)
+    }
+}
+
 macro_rules! impl_serde_for_symbol {
     ($name:ident, $ty:ty) => {
         impl ::serde::Serialize for $crate::symbol::$name {
@@ -126,7 +542,6 @@ mod tests {
         symbol::{SymbolU16, SymbolU32, SymbolUsize},
         Symbol,
     };
-    use serde_json;
 
     fn symbol_round_trip_serializes<S>(symbol: S) -> bool
     where
@@ -173,7 +588,7 @@ mod tests {
             SymbolUsize::try_from_usize(42).unwrap()
         ));
         assert!(symbol_round_trip_serializes(
-            SymbolUsize::try_from_usize(usize::MAX as usize - 1).unwrap()
+            SymbolUsize::try_from_usize(usize::MAX - 1).unwrap()
         ));
     }
 
@@ -189,4 +604,199 @@ mod tests {
             usize::try_from_usize(usize::MAX).unwrap()
         ));
     }
+
+    #[cfg(feature = "backends")]
+    fn backend_round_trip_serializes<B>()
+    where
+        B: crate::backend::Backend<Symbol = SymbolU32>
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + PartialEq
+            + std::fmt::Debug,
+    {
+        let mut interner = crate::StringInterner::<B>::new();
+        for string in ["Tiger", "", "Horse", "Elefant", "Zebra", "Ä€"] {
+            interner.get_or_intern(string);
+        }
+        let serialized =
+            serde_json::to_string(interner.backend()).expect("serialization should succeed");
+        let backend: B = serde_json::from_str(&serialized).expect("deserialization should succeed");
+        assert_eq!(&backend, interner.backend());
+        let deserialized =
+            crate::StringInterner::<B>::from_backend(backend).expect("backend has no duplicates");
+        assert_eq!(deserialized, interner);
+        assert_eq!(deserialized.get("Zebra"), interner.get("Zebra"));
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn backends_round_trip() {
+        use crate::backend::{BucketBackend, BufferBackend, IndexedBufferBackend, StringBackend};
+        backend_round_trip_serializes::<BucketBackend<SymbolU32>>();
+        backend_round_trip_serializes::<BufferBackend<SymbolU32>>();
+        backend_round_trip_serializes::<IndexedBufferBackend<SymbolU32>>();
+        backend_round_trip_serializes::<StringBackend<SymbolU32>>();
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn string_backend_rejects_invalid_ends() {
+        use crate::backend::StringBackend;
+        let from_json = |json| serde_json::from_str::<StringBackend<SymbolU32>>(json);
+        assert!(from_json(r#"[[2,5],"abcde"]"#).is_ok());
+        // End offsets must be ascending.
+        assert!(from_json(r#"[[3,2,5],"abcde"]"#).is_err());
+        // The last end offset must match the length of the buffer.
+        assert!(from_json(r#"[[2,4],"abcde"]"#).is_err());
+        assert!(from_json(r#"[[2,6],"abcde"]"#).is_err());
+        assert!(from_json(r#"[[],"abcde"]"#).is_err());
+        // End offsets must lie on `char` boundaries.
+        assert!(from_json(r#"[[1,2],"Ä"]"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn bucket_backend_rejects_invalid_ends() {
+        use crate::backend::BucketBackend;
+        let from_json = |json| serde_json::from_str::<BucketBackend<SymbolU16>>(json);
+        assert!(from_json(r#"[[2,5],"abcde"]"#).is_ok());
+        assert!(from_json(r#"[[3,2,5],"abcde"]"#).is_err());
+        assert!(from_json(r#"[[2,4],"abcde"]"#).is_err());
+        assert!(from_json(r#"[[1,2],"Ä"]"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn buffer_backends_reject_invalid_buffers() {
+        use crate::backend::{BufferBackend, IndexedBufferBackend};
+        let buffer = |json| serde_json::from_str::<BufferBackend<SymbolU32>>(json);
+        let indexed = |json| serde_json::from_str::<IndexedBufferBackend<SymbolU32>>(json);
+        assert!(buffer("[2,[1,97,0]]").is_ok());
+        assert!(indexed("[1,97,0]").is_ok());
+        // The number of strings must match the buffer.
+        assert!(buffer("[1,[1,97,0]]").is_err());
+        assert!(buffer("[3,[1,97,0]]").is_err());
+        // Strings must not exceed the buffer.
+        assert!(buffer("[1,[2,97]]").is_err());
+        assert!(indexed("[2,97]").is_err());
+        // Length prefixes must be complete.
+        assert!(buffer("[1,[128]]").is_err());
+        assert!(indexed("[128]").is_err());
+        // Strings must be valid UTF-8.
+        assert!(buffer("[1,[1,255]]").is_err());
+        assert!(indexed("[1,255]").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn backends_reject_too_many_strings_for_symbol() {
+        use crate::backend::StringBackend;
+        use alloc::vec::Vec;
+        let len = usize::from(u16::MAX) + 1;
+        let ends = (1..=len).collect::<Vec<_>>();
+        let buffer = "a".repeat(len);
+        let json = serde_json::to_string(&(ends, buffer)).unwrap();
+        assert!(serde_json::from_str::<StringBackend<SymbolU16>>(&json).is_err());
+        assert!(serde_json::from_str::<StringBackend<SymbolU32>>(&json).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn from_backend_rejects_duplicates() {
+        use crate::{backend::StringBackend, Error, StringInterner};
+        let backend: StringBackend<SymbolU32> =
+            serde_json::from_str(r#"[[5,10,15],"TigerHorseTiger"]"#).unwrap();
+        assert_eq!(
+            StringInterner::<_>::from_backend(backend).unwrap_err(),
+            Error::DuplicateString
+        );
+    }
+
+    #[cfg(feature = "backends")]
+    fn with_symbols_round_trip_serializes<B>()
+    where
+        B: crate::backend::Backend<Symbol = SymbolU32> + PartialEq + std::fmt::Debug,
+    {
+        use crate::{StringInterner, WithSymbols};
+        let mut interner = StringInterner::<B>::new();
+        let symbols = ["Tiger", "", "Horse", "Elefant", "Zebra", "Ä€"]
+            .map(|string| (interner.get_or_intern(string), string));
+        let serialized =
+            serde_json::to_string(&WithSymbols(&interner)).expect("serialization should succeed");
+        let WithSymbols(deserialized): WithSymbols<StringInterner<B>> =
+            serde_json::from_str(&serialized).expect("deserialization should succeed");
+        for (symbol, string) in symbols {
+            assert_eq!(deserialized.resolve(symbol), Some(string));
+        }
+        assert_eq!(deserialized, interner);
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn with_symbols_round_trips() {
+        use crate::backend::{BucketBackend, BufferBackend, IndexedBufferBackend, StringBackend};
+        with_symbols_round_trip_serializes::<BucketBackend<SymbolU32>>();
+        with_symbols_round_trip_serializes::<BufferBackend<SymbolU32>>();
+        with_symbols_round_trip_serializes::<IndexedBufferBackend<SymbolU32>>();
+        with_symbols_round_trip_serializes::<StringBackend<SymbolU32>>();
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn with_symbols_rejects_mismatching_symbols() {
+        use crate::{
+            backend::{BufferBackend, StringBackend},
+            StringInterner,
+            WithSymbols,
+        };
+        type Interner = WithSymbols<StringInterner<StringBackend<SymbolU16>>>;
+        let from_json = |json| serde_json::from_str::<Interner>(json);
+        assert!(from_json(r#"[[0,"Tiger"],[1,"Horse"]]"#).is_ok());
+        // Gaps between symbols.
+        assert!(from_json(r#"[[0,"Tiger"],[2,"Horse"]]"#).is_err());
+        assert!(from_json(r#"[[1,"Tiger"]]"#).is_err());
+        // Duplicate symbols or strings.
+        assert!(from_json(r#"[[0,"Tiger"],[0,"Horse"]]"#).is_err());
+        assert!(from_json(r#"[[0,"Tiger"],[1,"Tiger"]]"#).is_err());
+        // Symbols that are out of range.
+        assert!(from_json(r#"[[65535,"Tiger"]]"#).is_err());
+        assert!(from_json(r#"[[18446744073709551616,"Tiger"]]"#).is_err());
+        // Symbols of the buffer backend are not contiguous.
+        let from_json = |json| {
+            serde_json::from_str::<WithSymbols<StringInterner<BufferBackend<SymbolU32>>>>(json)
+        };
+        assert!(from_json(r#"[[0,"Tiger"],[6,"Horse"]]"#).is_ok());
+        assert!(from_json(r#"[[0,"Tiger"],[1,"Horse"]]"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "backends")]
+    fn borrowed_interner_round_trips() {
+        use crate::{BorrowedInterner, StringInterner};
+        let mut interner = <StringInterner<crate::DefaultBackend>>::new();
+        for string in ["Tiger", "", "Horse", "Ä€"] {
+            interner.get_or_intern(string);
+        }
+        let serialized = serde_json::to_string(&interner).expect("serialization should succeed");
+        let borrowed: BorrowedInterner<'_, SymbolU32> =
+            serde_json::from_str(&serialized).expect("deserialization should succeed");
+        assert_eq!(borrowed.len(), interner.len());
+        for (symbol, string) in &interner {
+            assert_eq!(borrowed.resolve(symbol), Some(string));
+            assert_eq!(borrowed.get(string), Some(symbol));
+        }
+        // The deserialized strings point into the serialized input.
+        let input = serialized.as_bytes().as_ptr_range();
+        assert!(borrowed
+            .iter()
+            .all(|(_, string)| input.contains(&string.as_ptr())));
+        let reserialized = serde_json::to_string(&borrowed).expect("serialization should succeed");
+        assert_eq!(reserialized, serialized);
+    }
+
+    #[test]
+    fn borrowed_interner_rejects_escaped_strings() {
+        use crate::BorrowedInterner;
+        assert!(serde_json::from_str::<BorrowedInterner>(r#"["Tiger\nHorse"]"#).is_err());
+    }
 }
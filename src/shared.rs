@@ -0,0 +1,366 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "backends")]
+use crate::backend::BucketBackend;
+use crate::{backend::Backend, symbol::SymbolMap, Error, StringInterner, Symbol};
+use alloc::collections::BTreeSet;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+};
+use hashbrown::DefaultHashBuilder;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// A string interner that is filled by publishing [`LocalInterner`]s into it.
+///
+/// Every [`LocalInterner`] is created with a ticket via [`SharedInterner::local`].
+/// Local interners are published in the order of their tickets, waiting for all
+/// local interners with earlier tickets to be published or dropped first.
+/// Therefore the symbols of the shared interner only depend on the order in which
+/// the local interners have been created and not on the scheduling of the threads
+/// that fill and publish them.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::{DefaultBackend, SharedInterner, Symbol};
+/// let shared = <SharedInterner<DefaultBackend>>::new();
+/// let locals = [["Tiger", "Horse"], ["Horse", "Zebra"]].map(|strings| (shared.local(), strings));
+/// let maps = std::thread::scope(|scope| {
+///     let handles = locals.map(|(mut local, strings)| {
+///         scope.spawn(move || {
+///             let symbols = strings.map(|string| local.get_or_intern(string));
+///             (symbols, local.publish())
+///         })
+///     });
+///     handles.map(|handle| handle.join().unwrap())
+/// });
+/// let interner = shared.into_inner();
+/// // The strings of the first local interner are always published first.
+/// assert_eq!(interner.get("Zebra").map(|symbol| symbol.to_usize()), Some(2));
+/// for (symbols, map) in maps {
+///     for symbol in symbols {
+///         assert!(interner.resolve(map.get(symbol).unwrap()).is_some());
+///     }
+/// }
+/// ```
+pub struct SharedInterner<B, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    state: Mutex<State<B, H>>,
+    published: Condvar,
+    hasher: H,
+}
+
+/// The state of a [`SharedInterner`] that is protected by its lock.
+struct State<B, H>
+where
+    B: Backend,
+{
+    interner: StringInterner<B, H>,
+    /// The ticket of the next created local interner.
+    next_ticket: usize,
+    /// The ticket of the next local interner to be published.
+    next_publish: usize,
+    /// The tickets of local interners that have been dropped without being published.
+    abandoned: BTreeSet<usize>,
+}
+
+impl<B, H> State<B, H>
+where
+    B: Backend,
+{
+    /// Finishes the turn of the local interner with the `next_publish` ticket.
+    fn advance(&mut self) {
+        self.next_publish += 1;
+        while self.abandoned.remove(&self.next_publish) {
+            self.next_publish += 1;
+        }
+    }
+}
+
+impl<B, H> Debug for SharedInterner<B, H>
+where
+    B: Backend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedInterner")
+            .field("interner", &self.lock().interner)
+            .finish()
+    }
+}
+
+impl<B, H> Default for SharedInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, H> SharedInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone + Default,
+{
+    /// Creates a new empty `SharedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(H::default())
+    }
+}
+
+impl<B, H> SharedInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    /// Creates a new empty `SharedInterner` with the given hasher.
+    ///
+    /// The hasher is also used by all [`LocalInterner`]s of the shared interner.
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            state: Mutex::new(State {
+                interner: StringInterner::with_hasher(hash_builder.clone()),
+                next_ticket: 0,
+                next_publish: 0,
+                abandoned: BTreeSet::new(),
+            }),
+            published: Condvar::new(),
+            hasher: hash_builder,
+        }
+    }
+
+    /// Locks the state of the shared interner.
+    fn lock(&self) -> MutexGuard<'_, State<B, H>> {
+        // A panic while holding the lock cannot leave the state inconsistent
+        // since the interner is only ever extended by complete strings.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Creates a new empty [`LocalInterner`] with the next ticket.
+    ///
+    /// # Note
+    ///
+    /// Create the local interners in a deterministic order, for example on a single
+    /// thread before distributing them, in order to get deterministic symbols.
+    pub fn local(&self) -> LocalInterner<'_, B, H> {
+        let mut state = self.lock();
+        let ticket = state.next_ticket;
+        state.next_ticket += 1;
+        LocalInterner {
+            shared: self,
+            ticket,
+            interner: StringInterner::with_hasher(self.hasher.clone()),
+            published: false,
+        }
+    }
+
+    /// Returns the number of strings published to the shared interner.
+    pub fn len(&self) -> usize {
+        self.lock().interner.len()
+    }
+
+    /// Returns `true` if no strings have been published to the shared interner.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given string if it has been published.
+    pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+    {
+        self.lock().interner.get(string)
+    }
+
+    /// Returns the underlying string interner holding all published strings.
+    pub fn into_inner(self) -> StringInterner<B, H> {
+        self.state
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .interner
+    }
+}
+
+#[cfg(feature = "backends")]
+impl<S, H> SharedInterner<BucketBackend<S>, H>
+where
+    S: Symbol,
+    H: BuildHasher + Clone,
+{
+    /// Returns the string for the given published `symbol` if any.
+    ///
+    /// Unlike publishing this does not wait for any [`LocalInterner`] and only
+    /// holds the lock of the shared interner while looking up the symbol.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let string: *const str = self.lock().interner.resolve(symbol)?;
+        // SAFETY: The bucket backend never moves or deallocates its interned
+        //         strings while it is alive and the shared interner never
+        //         removes strings from its interner. Therefore the string lives
+        //         as long as `self` even after the lock guard has been dropped.
+        Some(unsafe { &*string })
+    }
+}
+
+/// A string interner that is published into a [`SharedInterner`] once filled.
+///
+/// This is constructed by [`SharedInterner::local`].
+///
+/// Dropping a local interner without publishing it skips its turn.
+pub struct LocalInterner<'a, B, H = DefaultHashBuilder>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    shared: &'a SharedInterner<B, H>,
+    ticket: usize,
+    interner: StringInterner<B, H>,
+    published: bool,
+}
+
+impl<B, H> Debug for LocalInterner<'_, B, H>
+where
+    B: Backend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalInterner")
+            .field("ticket", &self.ticket)
+            .field("interner", &self.interner)
+            .finish()
+    }
+}
+
+impl<B, H> LocalInterner<'_, B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    /// Returns the number of strings interned by the local interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    /// Returns `true` if the local interner has no interned strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Returns the local symbol for the given string if any.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.get(string)
+    }
+
+    /// Interns the given string into the local interner.
+    ///
+    /// Returns a local symbol for resolution into the original string.
+    ///
+    /// # Panics
+    ///
+    /// If the local interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+    {
+        self.interner.get_or_intern(string)
+    }
+
+    /// Interns the given string into the local interner.
+    ///
+    /// Returns a local symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If the local interner already interns the maximum number of strings possible
+    /// by the chosen symbol type. In this case the local interner remains unchanged.
+    #[inline]
+    pub fn try_get_or_intern<T>(&mut self, string: T) -> Result<<B as Backend>::Symbol, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.interner.try_get_or_intern(string)
+    }
+
+    /// Returns the string for the given local `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns an iterator that yields all locally interned strings and their local symbols.
+    #[inline]
+    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
+        self.interner.iter()
+    }
+
+    /// Publishes all strings of the local interner into its [`SharedInterner`].
+    ///
+    /// Blocks until all local interners with earlier tickets have been published or dropped.
+    ///
+    /// Returns a [`SymbolMap`] that maps the local symbols to the symbols of the shared interner.
+    ///
+    /// # Panics
+    ///
+    /// If the shared interner runs out of symbols.
+    ///
+    /// # Note
+    ///
+    /// Publishing a local interner while holding an unpublished local interner
+    /// with an earlier ticket of the same shared interner never returns.
+    pub fn publish(mut self) -> SymbolMap<<B as Backend>::Symbol, <B as Backend>::Symbol> {
+        let state = self.shared.lock();
+        let mut state = self
+            .shared
+            .published
+            .wait_while(state, |state| state.next_publish != self.ticket)
+            .unwrap_or_else(PoisonError::into_inner);
+        let map = state.interner.merge(&self.interner);
+        self.published = true;
+        state.advance();
+        self.shared.published.notify_all();
+        map
+    }
+}
+
+impl<B, H> Drop for LocalInterner<'_, B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Clone,
+{
+    fn drop(&mut self) {
+        if self.published {
+            return;
+        }
+        let mut state = self.shared.lock();
+        if state.next_publish == self.ticket {
+            state.advance();
+            self.shared.published.notify_all();
+        } else {
+            state.abandoned.insert(self.ticket);
+        }
+    }
+}
@@ -5,7 +5,11 @@
 //! method returns `Symbol` types that allow to look-up the original string
 //! using [`StringInterner::resolve`](`crate::StringInterner::resolve`).
 
-use core::num::{NonZeroU16, NonZeroU32, NonZeroUsize};
+use alloc::vec::Vec;
+use core::{
+    marker::PhantomData,
+    num::{NonZeroU16, NonZeroU32, NonZeroUsize},
+};
 
 /// Types implementing this trait can be used as symbols for string interners.
 ///
@@ -31,7 +35,6 @@ pub trait Symbol: Copy + Eq {
 /// # Panics
 ///
 /// Panics if the conversion is invalid.
-#[cfg(feature = "backends")]
 #[inline]
 pub(crate) fn expect_valid_symbol<S>(index: usize) -> S
 where
@@ -76,7 +79,7 @@ macro_rules! gen_symbol_for {
         impl Symbol for $name {
             #[inline]
             fn try_from_usize(index: usize) -> Option<Self> {
-                Self::new(index as $base_ty)
+                <$base_ty>::try_from(index).ok().and_then(Self::new)
             }
 
             #[inline]
@@ -105,6 +108,88 @@ gen_symbol_for!(
     struct SymbolUsize(NonZeroUsize; usize);
 );
 
+/// A mapping from the symbols of one interner to the symbols of another.
+///
+/// This is returned by operations that change the symbols of interned strings,
+/// such as [`StringInterner::compact`](`crate::StringInterner::compact`), so that users
+/// are able to rewrite their own data structures.
+///
+/// # Note
+///
+/// The mapping is stored densely and indexed by the `usize` value of the source symbols.
+/// Therefore its memory consumption is proportional to the greatest mapped source symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolMap<Src, Dst> {
+    map: Vec<Option<Dst>>,
+    len: usize,
+    marker: PhantomData<fn() -> Src>,
+}
+
+impl<Src, Dst> Default for SymbolMap<Src, Dst> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            map: Vec::new(),
+            len: 0,
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<Src, Dst> SymbolMap<Src, Dst>
+where
+    Src: Symbol,
+    Dst: Symbol,
+{
+    /// Creates a new empty symbol map with capacity for `cap` source symbols.
+    #[inline]
+    pub(crate) fn with_capacity(cap: usize) -> Self {
+        Self {
+            map: Vec::with_capacity(cap),
+            len: 0,
+            marker: Default::default(),
+        }
+    }
+
+    /// Maps the `src` symbol to the `dst` symbol.
+    pub(crate) fn insert(&mut self, src: Src, dst: Dst) {
+        let index = src.to_usize();
+        if index >= self.map.len() {
+            self.map.resize(index + 1, None);
+        }
+        if self.map[index].replace(dst).is_none() {
+            self.len += 1;
+        }
+    }
+
+    /// Returns the symbol that `src` is mapped to if any.
+    #[inline]
+    pub fn get(&self, src: Src) -> Option<Dst> {
+        self.map.get(src.to_usize()).copied().flatten()
+    }
+
+    /// Returns the number of mapped symbols.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no symbols are mapped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over all mapped symbol pairs ordered by their source symbols.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Src, Dst)> + '_ {
+        self.map
+            .iter()
+            .enumerate()
+            .filter_map(|(index, dst)| dst.map(|dst| (expect_valid_symbol(index), dst)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,6 +223,7 @@ mod tests {
             })
         );
         assert_eq!(SymbolU16::try_from_usize(u16::MAX as usize), None);
+        assert_eq!(SymbolU16::try_from_usize(u16::MAX as usize + 1), None);
         assert_eq!(SymbolU16::try_from_usize(usize::MAX), None);
     }
 
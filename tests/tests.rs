@@ -1,7 +1,16 @@
 mod allocator;
 
 use allocator::TracingAllocator;
-use string_interner::{backend, DefaultHashBuilder, DefaultSymbol, Symbol};
+use string_interner::{
+    backend::{self, Backend, TruncatableBackend},
+    symbol::SymbolU16,
+    DefaultHashBuilder,
+    DefaultSymbol,
+    Entry,
+    Error,
+    RefCountedInterner,
+    Symbol,
+};
 
 #[global_allocator]
 static ALLOCATOR: TracingAllocator = TracingAllocator::new();
@@ -19,6 +28,81 @@ where
     S::try_from_usize(index).expect("encountered invalid symbol")
 }
 
+/// Interns unique strings into an interner using 16-bit symbols until it runs
+/// out of symbols and asserts that the failing intern left it unchanged.
+fn assert_try_get_or_intern_out_of_symbols<B>()
+where
+    B: Backend<Symbol = SymbolU16>,
+{
+    let mut interner = string_interner::StringInterner::<B, DefaultHashBuilder>::new();
+    let mut symbols = Vec::new();
+    let error =
+        (0..)
+            .map(|i| i.to_string())
+            .find_map(|string| match interner.try_get_or_intern(&string) {
+                Ok(symbol) => {
+                    symbols.push(symbol);
+                    None
+                }
+                Err(error) => Some((string, error)),
+            });
+    let (string, error) = error.unwrap();
+    assert_eq!(error, Error::OutOfSymbols);
+    assert_eq!(interner.len(), symbols.len());
+    assert_eq!(interner.get(&string), None);
+    assert_eq!(
+        interner.try_get_or_intern(&string),
+        Err(Error::OutOfSymbols)
+    );
+    assert_eq!(
+        interner.try_get_or_intern_static("static"),
+        Err(Error::OutOfSymbols)
+    );
+    // Already interned strings can still be queried and resolved.
+    for (i, &symbol) in symbols.iter().enumerate() {
+        let expected = i.to_string();
+        assert_eq!(interner.resolve(symbol), Some(expected.as_str()));
+        assert_eq!(interner.try_get_or_intern(&expected), Ok(symbol));
+    }
+}
+
+/// Interns strings speculatively into an interner and asserts that rolling back
+/// to a checkpoint only removes the strings interned after the checkpoint.
+fn assert_rollback_to_works<B>()
+where
+    B: TruncatableBackend<Symbol = DefaultSymbol>,
+{
+    let mut interner = string_interner::StringInterner::<B, DefaultHashBuilder>::new();
+    let aa = interner.get_or_intern("aa");
+    let bb = interner.get_or_intern_static("bb");
+    let checkpoint = interner.checkpoint();
+    let cc = interner.get_or_intern("cc");
+    let dd = interner.get_or_intern_static("dd");
+    assert_eq!(interner.get_or_intern("aa"), aa);
+    assert_eq!(interner.len(), 4);
+    interner.rollback_to(checkpoint);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.get("cc"), None);
+    assert_eq!(interner.get("dd"), None);
+    assert_eq!(interner.resolve(cc), None);
+    assert_eq!(interner.resolve(dd), None);
+    assert!(Iterator::eq(interner.iter(), [(aa, "aa"), (bb, "bb")]));
+    // Rolling back to the same checkpoint again has no effect.
+    interner.rollback_to(checkpoint);
+    assert_eq!(interner.len(), 2);
+    // Strings interned after the rollback are deduplicated as usual.
+    let ee = interner.get_or_intern("ee");
+    let cc = interner.get_or_intern("cc");
+    assert_eq!(interner.get_or_intern("ee"), ee);
+    assert_eq!(interner.get_or_intern("bb"), bb);
+    assert_eq!(interner.resolve(cc), Some("cc"));
+    assert_eq!(interner.resolve(ee), Some("ee"));
+    assert_eq!(interner.resolve(aa), Some("aa"));
+    assert_eq!(interner.len(), 4);
+    interner.rollback_to(interner.checkpoint());
+    assert_eq!(interner.len(), 4);
+}
+
 /// Stats for the backend.
 pub trait BackendStats {
     /// The expected minimum memory overhead for this string interner backend.
@@ -49,6 +133,14 @@ impl BackendStats for backend::StringBackend<DefaultSymbol> {
     const NAME: &'static str = "StringBackend";
 }
 
+impl BackendStats for backend::CStrBackend<DefaultSymbol> {
+    const MIN_OVERHEAD: f64 = 1.76;
+    const MAX_OVERHEAD: f64 = 1.99;
+    const MAX_ALLOCATIONS: usize = 63;
+    const MAX_DEALLOCATIONS: usize = 60;
+    const NAME: &'static str = "CStrBackend";
+}
+
 impl BackendStats for backend::BufferBackend<DefaultSymbol> {
     const MIN_OVERHEAD: f64 = 1.35;
     const MAX_OVERHEAD: f64 = 1.58;
@@ -57,6 +149,22 @@ impl BackendStats for backend::BufferBackend<DefaultSymbol> {
     const NAME: &'static str = "BufferBackend";
 }
 
+impl BackendStats for backend::FreeListBackend<DefaultSymbol> {
+    const MIN_OVERHEAD: f64 = 2.5;
+    const MAX_OVERHEAD: f64 = 2.8;
+    const MAX_ALLOCATIONS: usize = 1_000_040;
+    const MAX_DEALLOCATIONS: usize = 38;
+    const NAME: &'static str = "FreeListBackend";
+}
+
+impl BackendStats for backend::IndexedBufferBackend<DefaultSymbol> {
+    const MIN_OVERHEAD: f64 = 1.55;
+    const MAX_OVERHEAD: f64 = 1.78;
+    const MAX_ALLOCATIONS: usize = 75;
+    const MAX_DEALLOCATIONS: usize = 71;
+    const NAME: &'static str = "IndexedBufferBackend";
+}
+
 /// Memory profiling stats.
 pub struct ProfilingStats {
     /// The minimum memory usage overhead as factor.
@@ -277,6 +385,64 @@ macro_rules! gen_tests_for_backend {
             assert_eq!(interner.len(), 3);
         }
 
+        #[test]
+        fn try_get_or_intern_works() {
+            let mut interner = StringInterner::new();
+            let aa = interner.try_get_or_intern("aa").unwrap();
+            let bb = interner.try_get_or_intern_static("bb").unwrap();
+            assert_ne!(aa, bb);
+            assert_eq!(interner.try_get_or_intern("aa"), Ok(aa));
+            assert_eq!(interner.try_get_or_intern_static("bb"), Ok(bb));
+            assert_eq!(interner.get_or_intern("bb"), bb);
+            assert_eq!(interner.len(), 2);
+        }
+
+        #[test]
+        fn get_or_intern_with_status_works() {
+            let mut interner = StringInterner::new();
+            let (aa, is_new) = interner.get_or_intern_with_status("aa");
+            assert!(is_new);
+            let (bb, is_new) = interner.get_or_intern_with_status("bb");
+            assert!(is_new);
+            assert_ne!(aa, bb);
+            assert_eq!(interner.get_or_intern_with_status("aa"), (aa, false));
+            assert_eq!(interner.get_or_intern_with_status("bb"), (bb, false));
+            let cc = interner.get_or_intern("cc");
+            assert_eq!(interner.get_or_intern_with_status("cc"), (cc, false));
+            assert_eq!(interner.len(), 3);
+        }
+
+        #[test]
+        fn entry_works() {
+            let mut interner = StringInterner::new();
+            let aa = interner.get_or_intern("aa");
+            match interner.entry("aa") {
+                Entry::Occupied(occupied) => {
+                    assert_eq!(occupied.key(), "aa");
+                    assert_eq!(occupied.symbol(), aa);
+                }
+                Entry::Vacant(_) => panic!("expected an occupied entry for 'aa'"),
+            }
+            let bb = match interner.entry("bb") {
+                Entry::Occupied(_) => panic!("expected a vacant entry for 'bb'"),
+                Entry::Vacant(vacant) => {
+                    assert_eq!(vacant.key(), "bb");
+                    vacant.intern()
+                }
+            };
+            assert_ne!(aa, bb);
+            assert_eq!(interner.len(), 2);
+            assert_eq!(interner.get("bb"), Some(bb));
+            assert_eq!(interner.resolve(bb), Some("bb"));
+            assert_eq!(interner.entry("aa").symbol(), Some(aa));
+            assert_eq!(interner.entry("bb").or_intern(), bb);
+            assert_eq!(interner.entry("cc").symbol(), None);
+            assert_eq!(interner.len(), 2);
+            let cc = interner.entry("cc").try_or_intern().unwrap();
+            assert_eq!(interner.get("cc"), Some(cc));
+            assert_eq!(interner.len(), 3);
+        }
+
         #[test]
         fn resolve_works() {
             let mut interner = StringInterner::new();
@@ -381,6 +547,132 @@ macro_rules! gen_tests_for_backend {
             assert!(Iterator::eq(expected_iter, &interner));
         }
 
+        #[test]
+        fn compact_works() {
+            let mut interner = StringInterner::new();
+            let strings = ["aa", "bb", "cc", "dd", "ee", "ff"];
+            let symbols = strings.iter().map(|s| interner.get_or_intern(s)).collect::<Vec<_>>();
+            let map = interner.compact();
+            assert_eq!(map.len(), strings.len());
+            assert_eq!(interner.len(), strings.len());
+            for (symbol, string) in symbols.into_iter().zip(strings) {
+                let new_symbol = map.get(symbol).unwrap();
+                assert_eq!(interner.resolve(new_symbol), Some(string));
+                assert_eq!(interner.get(string), Some(new_symbol));
+                assert_eq!(interner.get_or_intern(string), new_symbol);
+            }
+            assert_eq!(interner.len(), strings.len());
+        }
+
+        #[test]
+        fn merge_works() {
+            let mut interner = StringInterner::new();
+            let aa = interner.get_or_intern("aa");
+            let bb = interner.get_or_intern("bb");
+            let mut other =
+                string_interner::StringInterner::<backend::BufferBackend<SymbolU16>, DefaultHashBuilder>::new();
+            let other_symbols = ["cc", "bb", "dd", "aa"].map(|s| other.get_or_intern(s));
+            let map = interner.merge(&other);
+            assert_eq!(map.len(), other.len());
+            assert_eq!(interner.len(), 4);
+            assert_eq!(map.get(other_symbols[1]), Some(bb));
+            assert_eq!(map.get(other_symbols[3]), Some(aa));
+            for (other_symbol, string) in &other {
+                let symbol = map.get(other_symbol).unwrap();
+                assert_eq!(interner.resolve(symbol), Some(string));
+                assert_eq!(interner.get(string), Some(symbol));
+            }
+            // Merging the same interner again does not intern any new strings.
+            assert_eq!(interner.merge(&other), map);
+            assert_eq!(interner.len(), 4);
+        }
+
+        #[test]
+        fn freeze_works() {
+            let strings = ["aa", "bb", "cc", "dd"];
+            let interner = strings.iter().collect::<StringInterner>();
+            let frozen = interner.clone().freeze();
+            let compacted = interner.clone().freeze_compact();
+            assert_eq!(frozen.len(), strings.len());
+            assert_eq!(compacted, frozen);
+            for (symbol, string) in &interner {
+                assert_eq!(frozen.get(string), Some(symbol));
+                assert_eq!(frozen.resolve(symbol), Some(string));
+                assert_eq!(compacted.get(string), Some(symbol));
+                assert_eq!(compacted.resolve(symbol), Some(string));
+            }
+            assert_eq!(frozen.get("ee"), None);
+            assert!(Iterator::eq(frozen.iter(), interner.iter()));
+            fn assert_sync<T: Sync>(_: &T) {}
+            assert_sync(&frozen);
+        }
+
+        #[test]
+        fn write_to_read_from_works() {
+            let interner = ["aa", "", "bb", "ä€", "cc"].iter().collect::<StringInterner>();
+            let mut snapshot = Vec::new();
+            interner.write_to(&mut snapshot).unwrap();
+            let restored = StringInterner::read_from(&snapshot[..]).unwrap();
+            assert_eq!(restored, interner);
+            for (symbol, string) in &interner {
+                assert_eq!(restored.resolve(symbol), Some(string));
+            }
+            // Corrupting any byte of the snapshot is detected.
+            for i in 0..snapshot.len() {
+                let mut corrupted = snapshot.clone();
+                corrupted[i] ^= 0x01;
+                assert!(StringInterner::read_from(&corrupted[..]).is_err());
+            }
+            // Truncated snapshots are rejected.
+            for len in 0..snapshot.len() {
+                assert!(StringInterner::read_from(&snapshot[..len]).is_err());
+            }
+        }
+
+        #[test]
+        fn capacity_works() {
+            let interner = StringInterner::new();
+            assert_eq!(interner.capacity(), 0);
+            let interner = StringInterner::with_capacity(100);
+            assert!(interner.capacity() >= 100);
+        }
+
+        #[test]
+        fn reserve_works() {
+            let mut interner = StringInterner::new();
+            let aa = interner.get_or_intern("aa");
+            let bb = interner.get_or_intern("bb");
+            interner.reserve(100);
+            assert!(interner.capacity() >= 102);
+            assert_eq!(interner.len(), 2);
+            assert_eq!(interner.get("aa"), Some(aa));
+            assert_eq!(interner.get("bb"), Some(bb));
+            assert_eq!(interner.resolve(aa), Some("aa"));
+            let cc = interner.get_or_intern("cc");
+            assert_eq!(interner.get("cc"), Some(cc));
+            assert_eq!(interner.len(), 3);
+        }
+
+        #[test]
+        fn clear_works() {
+            let mut interner = StringInterner::with_capacity(100);
+            let strings = ["aa", "bb", "cc", "dd", "ee", "ff"];
+            let symbols = strings.iter().map(|s| interner.get_or_intern(s)).collect::<Vec<_>>();
+            interner.clear();
+            assert!(interner.is_empty());
+            assert!(interner.capacity() >= 100);
+            assert_eq!(interner.iter().next(), None);
+            for string in strings {
+                assert_eq!(interner.get(string), None);
+            }
+            // Interning the same strings after clearing yields the same symbols.
+            for (symbol, string) in symbols.into_iter().zip(strings) {
+                assert_eq!(interner.get_or_intern(string), symbol);
+                assert_eq!(interner.resolve(symbol), Some(string));
+            }
+            assert_eq!(interner.len(), strings.len());
+        }
+
         #[test]
         fn shrink_to_fit_works() {
             let mut interner = StringInterner::new();
@@ -415,16 +707,1417 @@ mod bucket_backend {
     use super::*;
 
     gen_tests_for_backend!(backend::BucketBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::BucketBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn rollback_to_works() {
+        assert_rollback_to_works::<backend::BucketBackend<DefaultSymbol>>();
+    }
+
+    #[test]
+    fn freeze_compact_packs_buckets() {
+        let mut interner = StringInterner::new();
+        let symbols = (0..1000)
+            .map(|i| interner.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+        let frozen = interner.freeze_compact();
+        let strings = symbols
+            .iter()
+            .map(|&symbol| frozen.resolve(symbol).unwrap())
+            .collect::<Vec<_>>();
+        for (i, string) in strings.iter().enumerate() {
+            assert_eq!(string, &i.to_string());
+        }
+        // All strings are stored back to back in a single buffer.
+        for pair in strings.windows(2) {
+            assert_eq!(
+                pair[0].as_ptr().wrapping_add(pair[0].len()),
+                pair[1].as_ptr()
+            );
+        }
+    }
 }
 
 mod string_backend {
     use super::*;
 
     gen_tests_for_backend!(backend::StringBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::StringBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn rollback_to_works() {
+        assert_rollback_to_works::<backend::StringBackend<DefaultSymbol>>();
+    }
+}
+
+mod cstr_backend {
+    use super::*;
+    use core::ffi::CStr;
+
+    gen_tests_for_backend!(backend::CStrBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::CStrBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn rollback_to_works() {
+        assert_rollback_to_works::<backend::CStrBackend<DefaultSymbol>>();
+    }
+
+    #[test]
+    fn resolve_cstr_works() {
+        let mut interner = StringInterner::new();
+        let main = interner.get_or_intern("main");
+        let empty = interner.get_or_intern("");
+        let interior = interner.get_or_intern("a\0b");
+        assert_eq!(interner.resolve_cstr(main), Some(c"main"));
+        assert_eq!(interner.resolve_cstr(empty), Some(c""));
+        assert_eq!(interner.resolve_cstr(interior), None);
+        assert_eq!(interner.resolve(interior), Some("a\0b"));
+        assert_eq!(interner.resolve_cstr(expect_valid_symbol(1000)), None);
+        let cstr: &CStr = interner.resolve_cstr(main).unwrap();
+        assert_eq!(cstr.to_bytes_with_nul(), b"main\0");
+        assert!(Iterator::eq(
+            interner.iter(),
+            [(main, "main"), (empty, ""), (interior, "a\0b")]
+        ));
+        assert_eq!(interner.iter().len(), 3);
+    }
+
+    #[test]
+    fn resolve_cstr_after_rollback_works() {
+        let mut interner = StringInterner::new();
+        let checkpoint = interner.checkpoint();
+        interner.get_or_intern("a\0b");
+        interner.rollback_to(checkpoint);
+        // The symbol of the rolled back string is reused for a string without interior NUL bytes.
+        let main = interner.get_or_intern("main");
+        assert_eq!(interner.resolve_cstr(main), Some(c"main"));
+        let interior = interner.get_or_intern("\0");
+        assert_eq!(interner.resolve_cstr(interior), None);
+        interner.clear();
+        let main = interner.get_or_intern("main");
+        assert_eq!(interner.resolve_cstr(main), Some(c"main"));
+    }
 }
 
 mod buffer_backend {
     use super::*;
 
     gen_tests_for_backend!(backend::BufferBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::BufferBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn rollback_to_works() {
+        assert_rollback_to_works::<backend::BufferBackend<DefaultSymbol>>();
+    }
+}
+
+mod indexed_buffer_backend {
+    use super::*;
+
+    gen_tests_for_backend!(backend::IndexedBufferBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::IndexedBufferBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn rollback_to_works() {
+        assert_rollback_to_works::<backend::IndexedBufferBackend<DefaultSymbol>>();
+    }
+
+    #[test]
+    fn symbols_are_contiguous() {
+        let mut interner = StringInterner::new();
+        for (index, string) in ["aa", "bb", "cc", "aa"].into_iter().enumerate() {
+            let symbol = interner.get_or_intern(string);
+            assert_eq!(symbol.to_usize(), index % 3);
+        }
+    }
+
+    #[test]
+    fn resolve_across_blocks_works() {
+        let strings = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let mut interner = StringInterner::new();
+        let empty = interner.checkpoint();
+        for string in &strings {
+            interner.get_or_intern(string);
+        }
+        for (index, string) in strings.iter().enumerate() {
+            let symbol = expect_valid_symbol(index);
+            assert_eq!(interner.resolve(symbol), Some(string.as_str()));
+            assert_eq!(unsafe { interner.resolve_unchecked(symbol) }, string);
+        }
+        assert!(Iterator::eq(
+            interner.iter().map(|(_, string)| string),
+            strings.iter().map(String::as_str)
+        ));
+        // Rolling back into the middle of a block keeps its earlier strings.
+        interner.rollback_to(empty);
+        assert!(interner.is_empty());
+        for string in &strings[..300] {
+            interner.get_or_intern(string);
+        }
+        let checkpoint = interner.checkpoint();
+        interner.get_or_intern("a");
+        interner.rollback_to(checkpoint);
+        assert_eq!(interner.get_or_intern("b").to_usize(), 300);
+        assert_eq!(interner.resolve(expect_valid_symbol(299)), Some("299"));
+    }
+
+    /// Returns the number of heap bytes used to intern the given `words` into an interner.
+    fn allocated_bytes<B>(words: &[String]) -> usize
+    where
+        B: Backend,
+    {
+        ALLOCATOR.reset();
+        ALLOCATOR.start_profiling();
+        let mut interner = string_interner::StringInterner::<B, DefaultHashBuilder>::new();
+        for word in words {
+            interner.get_or_intern(word);
+        }
+        interner.shrink_to_fit();
+        ALLOCATOR.end_profiling();
+        ALLOCATOR.stats().current_allocated_bytes()
+    }
+
+    #[test]
+    #[cfg_attr(any(miri, not(feature = "test-allocations")), ignore)]
+    fn test_memory_consumption_compared_to_other_backends() {
+        let words = (0..100_000)
+            .map(|i| format!("{:20}", i))
+            .collect::<Vec<_>>();
+        let indexed = allocated_bytes::<backend::IndexedBufferBackend<DefaultSymbol>>(&words);
+        let string = allocated_bytes::<backend::StringBackend<DefaultSymbol>>(&words);
+        let buffer = allocated_bytes::<backend::BufferBackend<DefaultSymbol>>(&words);
+        println!(
+            "IndexedBufferBackend = {indexed}, StringBackend = {string}, BufferBackend = {buffer}"
+        );
+        assert!(buffer < indexed);
+        assert!(indexed < string);
+    }
+}
+
+mod free_list_backend {
+    use super::*;
+
+    gen_tests_for_backend!(backend::FreeListBackend<DefaultSymbol>);
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        assert_try_get_or_intern_out_of_symbols::<backend::FreeListBackend<SymbolU16>>();
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut interner = StringInterner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        let cc = interner.get_or_intern("cc");
+        assert!(interner.remove(bb));
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("bb"), None);
+        assert_eq!(interner.resolve(bb), None);
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.resolve(cc), Some("cc"));
+        // Removing the same symbol twice or an invalid symbol has no effect.
+        assert!(!interner.remove(bb));
+        assert!(!interner.remove(expect_valid_symbol(1000)));
+        assert_eq!(interner.len(), 2);
+        assert!(Iterator::eq(interner.iter(), [(aa, "aa"), (cc, "cc")]));
+    }
+
+    #[test]
+    fn retain_works() {
+        let mut interner = StringInterner::new();
+        let strings = ["aa", "bb", "cc", "dd", "ee", "ff"];
+        let symbols = strings
+            .iter()
+            .map(|s| interner.get_or_intern(s))
+            .collect::<Vec<_>>();
+        let mut visited = Vec::new();
+        interner.retain(|symbol, string| {
+            visited.push((symbol, string.to_owned()));
+            symbol.to_usize() % 2 == 0
+        });
+        assert_eq!(visited.len(), strings.len());
+        assert_eq!(interner.len(), 3);
+        for (symbol, string) in symbols.into_iter().zip(strings) {
+            let expected = (symbol.to_usize() % 2 == 0).then_some(string);
+            assert_eq!(interner.resolve(symbol), expected);
+            assert_eq!(interner.get(string), expected.map(|_| symbol));
+        }
+    }
+
+    #[test]
+    fn compact_after_retain_works() {
+        let mut interner = StringInterner::new();
+        let strings = ["aa", "bb", "cc", "dd", "ee", "ff"];
+        let symbols = strings
+            .iter()
+            .map(|s| interner.get_or_intern(s))
+            .collect::<Vec<_>>();
+        interner.retain(|_, string| string != "aa" && string != "dd");
+        let map = interner.compact();
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.get(symbols[0]), None);
+        assert_eq!(map.get(symbols[3]), None);
+        // The retained strings are densely stored in their original order.
+        let expected = [(1, "bb"), (2, "cc"), (4, "ee"), (5, "ff")];
+        for (new_index, (old_index, string)) in expected.into_iter().enumerate() {
+            let new_symbol = map.get(symbols[old_index]).unwrap();
+            assert_eq!(new_symbol.to_usize(), new_index);
+            assert_eq!(interner.resolve(new_symbol), Some(string));
+            assert_eq!(interner.get(string), Some(new_symbol));
+        }
+        assert!(Iterator::eq(
+            map.iter().map(|(old, _)| old),
+            [1, 2, 4, 5].map(|index| symbols[index])
+        ));
+        assert_eq!(interner.get_or_intern("gg").to_usize(), 4);
+    }
+
+    #[test]
+    fn remove_reuses_symbols() {
+        let mut interner = StringInterner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        let cc = interner.get_or_intern("cc");
+        assert!(interner.remove(aa));
+        assert!(interner.remove(cc));
+        // The most recently removed symbols are handed out first.
+        let dd = interner.get_or_intern("dd");
+        let ee = interner.get_or_intern("ee");
+        let ff = interner.get_or_intern("ff");
+        assert_eq!(dd, cc);
+        assert_eq!(ee, aa);
+        assert_eq!(ff.to_usize(), 3);
+        assert_eq!(interner.len(), 4);
+        assert_eq!(interner.get("dd"), Some(dd));
+        assert_eq!(interner.get("cc"), None);
+        assert_eq!(interner.get_or_intern("bb"), bb);
+        assert_eq!(interner.resolve(ee), Some("ee"));
+        // Re-interning a removed string yields a valid symbol again.
+        let aa = interner.get_or_intern("aa");
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.len(), 5);
+    }
+}
+
+mod custom_backend {
+    use super::*;
+
+    /// A backend that only implements the required methods of [`Backend`].
+    #[derive(Debug, Default)]
+    struct VecBackend {
+        strings: Vec<String>,
+    }
+
+    impl Backend for VecBackend {
+        type Symbol = DefaultSymbol;
+        type Iter<'a> = std::iter::Map<
+            std::iter::Enumerate<std::slice::Iter<'a, String>>,
+            fn((usize, &'a String)) -> (DefaultSymbol, &'a str),
+        >;
+
+        fn with_capacity(cap: usize) -> Self {
+            Self {
+                strings: Vec::with_capacity(cap),
+            }
+        }
+
+        fn intern(&mut self, string: &str) -> Self::Symbol {
+            self.strings.push(string.to_owned());
+            expect_valid_symbol(self.strings.len() - 1)
+        }
+
+        fn shrink_to_fit(&mut self) {
+            self.strings.shrink_to_fit();
+        }
+
+        fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+            self.strings.get(symbol.to_usize()).map(String::as_str)
+        }
+
+        unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+            unsafe { self.strings.get_unchecked(symbol.to_usize()) }
+        }
+
+        fn iter(&self) -> Self::Iter<'_> {
+            self.strings
+                .iter()
+                .enumerate()
+                .map(|(index, string)| (expect_valid_symbol(index), string.as_str()))
+        }
+    }
+
+    type StringInterner = string_interner::StringInterner<VecBackend>;
+
+    #[test]
+    fn backend_without_try_intern_works() {
+        let mut interner = StringInterner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern_static("bb");
+        assert_eq!(
+            interner.try_get_or_intern("cc").map(|s| s.to_usize()),
+            Ok(2)
+        );
+        assert_eq!(interner.try_get_or_intern("aa"), Ok(aa));
+        assert_eq!(interner.get("bb"), Some(bb));
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    fn backend_without_capacity_works() {
+        let mut interner = StringInterner::with_capacity(100);
+        assert_eq!(interner.capacity(), 0);
+        let aa = interner.get_or_intern("aa");
+        interner.reserve(100);
+        assert_eq!(interner.get("aa"), Some(aa));
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("aa"), None);
+        assert_eq!(interner.get_or_intern("bb"), aa);
+    }
+}
+
+mod ref_counted_interner {
+    use super::*;
+
+    type Interner = RefCountedInterner<backend::FreeListBackend<DefaultSymbol>, DefaultHashBuilder>;
+
+    #[test]
+    fn get_or_intern_increments_ref_count() {
+        let mut interner = Interner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        assert_eq!(interner.get_or_intern("aa"), aa);
+        assert_eq!(interner.get_or_intern("aa"), aa);
+        assert_eq!(interner.ref_count(aa), 3);
+        assert_eq!(interner.ref_count(bb), 1);
+        assert_eq!(interner.ref_count(expect_valid_symbol(1000)), 0);
+        // Querying a string does not change its reference count.
+        assert_eq!(interner.get("aa"), Some(aa));
+        assert_eq!(interner.ref_count(aa), 3);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn release_works() {
+        let mut interner = Interner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        interner.get_or_intern("aa");
+        assert_eq!(interner.release(aa), Some(1));
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.release(aa), Some(0));
+        assert_eq!(interner.resolve(aa), None);
+        assert_eq!(interner.get("aa"), None);
+        assert_eq!(interner.ref_count(aa), 0);
+        // Releasing dead or invalid symbols has no effect.
+        assert_eq!(interner.release(aa), None);
+        assert_eq!(interner.release(expect_valid_symbol(1000)), None);
+        assert_eq!(interner.len(), 1);
+        assert!(Iterator::eq(interner.iter(), [(bb, "bb")]));
+    }
+
+    #[test]
+    fn reintern_after_release_works() {
+        let mut interner = Interner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        assert_eq!(interner.release(aa), Some(0));
+        let cc = interner.get_or_intern("cc");
+        let aa = interner.get_or_intern("aa");
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.resolve(bb), Some("bb"));
+        assert_eq!(interner.resolve(cc), Some("cc"));
+        assert_eq!(interner.ref_count(aa), 1);
+        assert_eq!(interner.ref_count(cc), 1);
+        assert_eq!(interner.get_or_intern("aa"), aa);
+        assert_eq!(interner.len(), 3);
+    }
+}
+
+mod concurrent_interner {
+    use super::*;
+    use string_interner::ConcurrentStringInterner;
+
+    type Interner = ConcurrentStringInterner<DefaultSymbol, DefaultHashBuilder>;
+
+    #[test]
+    fn get_or_intern_works() {
+        let interner = Interner::new();
+        assert!(interner.is_empty());
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern("bb");
+        assert_ne!(aa, bb);
+        assert_eq!(interner.get_or_intern("aa"), aa);
+        assert_eq!(interner.get("bb"), Some(bb));
+        assert_eq!(interner.get("cc"), None);
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.resolve(bb), Some("bb"));
+        assert_eq!(interner.resolve(expect_valid_symbol(1000)), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn single_shard_works() {
+        let interner = Interner::with_shards(1);
+        let symbols = ["aa", "bb", "cc"].map(|s| interner.get_or_intern(s));
+        // With a single shard the symbols are contiguous.
+        assert_eq!(symbols.map(|symbol| symbol.to_usize()), [0, 1, 2]);
+        assert_eq!(interner.shards(), 1);
+    }
+
+    #[test]
+    fn concurrent_get_or_intern_works() {
+        let interner = Interner::with_shards(4);
+        let strings = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let symbols = std::thread::scope(|scope| {
+            let handles = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        strings
+                            .iter()
+                            .map(|string| interner.get_or_intern(string))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        // All threads agree on the symbols of all strings.
+        for other in &symbols[1..] {
+            assert_eq!(other, &symbols[0]);
+        }
+        assert_eq!(interner.len(), strings.len());
+        for (symbol, string) in symbols[0].iter().zip(&strings) {
+            assert_eq!(interner.resolve(*symbol), Some(string.as_str()));
+        }
+    }
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols_works() {
+        let interner = ConcurrentStringInterner::<SymbolU16, DefaultHashBuilder>::with_shards(4);
+        let error = (0..).map(|i| i.to_string()).find_map(|string| {
+            interner
+                .try_get_or_intern(&string)
+                .err()
+                .map(|e| (string, e))
+        });
+        let (string, error) = error.unwrap();
+        assert_eq!(error, Error::OutOfSymbols);
+        assert_eq!(interner.get(&string), None);
+        assert_eq!(
+            interner.try_get_or_intern(&string),
+            Err(Error::OutOfSymbols)
+        );
+        assert_eq!(
+            interner.try_get_or_intern("0"),
+            Ok(interner.get("0").unwrap())
+        );
+    }
+}
+
+mod global_symbol {
+    use string_interner::global::GlobalSymbol;
+
+    #[test]
+    fn intern_works() {
+        let aa = GlobalSymbol::intern("global aa");
+        let bb = GlobalSymbol::intern(String::from("global bb"));
+        assert_ne!(aa, bb);
+        assert_eq!(GlobalSymbol::intern("global aa"), aa);
+        assert_eq!(GlobalSymbol::from("global bb"), bb);
+        assert_eq!(GlobalSymbol::get("global aa"), Some(aa));
+        assert_eq!(GlobalSymbol::get("global never interned"), None);
+        assert_eq!(aa.as_str(), "global aa");
+        assert_eq!(bb.to_string(), "global bb");
+        assert_eq!(format!("{aa:?}"), r#"GlobalSymbol("global aa")"#);
+    }
+
+    #[test]
+    fn intern_from_many_threads_works() {
+        let symbols = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..100)
+                            .map(|i| GlobalSymbol::intern(format!("global {i}")))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        for other in &symbols[1..] {
+            assert_eq!(other, &symbols[0]);
+        }
+        // The resolved strings outlive any local borrow of the interner.
+        let strings: Vec<&'static str> = symbols[0].iter().map(|symbol| symbol.as_str()).collect();
+        for (i, string) in strings.into_iter().enumerate() {
+            assert_eq!(string, format!("global {i}"));
+        }
+    }
+}
+
+mod shared_interner {
+    use super::*;
+    use string_interner::SharedInterner;
+
+    type Interner = SharedInterner<backend::StringBackend<DefaultSymbol>, DefaultHashBuilder>;
+
+    /// Publishes the `chunks` from threads spawned in reverse order of their tickets.
+    fn publish_chunks(chunks: &[Vec<String>]) -> Vec<(String, DefaultSymbol)> {
+        let shared = Interner::new();
+        let locals = chunks.iter().map(|_| shared.local()).collect::<Vec<_>>();
+        std::thread::scope(|scope| {
+            for (mut local, chunk) in locals.into_iter().zip(chunks).rev() {
+                scope.spawn(move || {
+                    let symbols = chunk
+                        .iter()
+                        .map(|string| local.get_or_intern(string))
+                        .collect::<Vec<_>>();
+                    let map = local.publish();
+                    assert!(symbols.into_iter().all(|symbol| map.get(symbol).is_some()));
+                });
+            }
+        });
+        shared
+            .into_inner()
+            .iter()
+            .map(|(symbol, string)| (string.to_owned(), symbol))
+            .collect()
+    }
+
+    #[test]
+    fn publish_is_deterministic() {
+        let chunks = (0..8)
+            .map(|chunk| {
+                (0..50)
+                    .map(|i| format!("{}", (chunk * 7 + i) % 97))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let expected = publish_chunks(&chunks);
+        for _ in 0..5 {
+            assert_eq!(publish_chunks(&chunks), expected);
+        }
+        // The strings are published in the order of the tickets.
+        let mut sequential = string_interner::StringInterner::<
+            backend::StringBackend<DefaultSymbol>,
+            DefaultHashBuilder,
+        >::new();
+        chunks.iter().flatten().for_each(|string| {
+            sequential.get_or_intern(string);
+        });
+        assert!(Iterator::eq(
+            sequential
+                .iter()
+                .map(|(symbol, string)| (string.to_owned(), symbol)),
+            expected
+        ));
+    }
+
+    #[test]
+    fn publish_maps_local_symbols() {
+        let shared = Interner::new();
+        let mut first = shared.local();
+        let mut second = shared.local();
+        let bb = second.get_or_intern("bb");
+        let second_aa = second.get_or_intern("aa");
+        let first_aa = first.get_or_intern("aa");
+        let first_map = first.publish();
+        let second_map = second.publish();
+        assert_eq!(first_map.get(first_aa), second_map.get(second_aa));
+        assert_eq!(shared.get("aa"), first_map.get(first_aa));
+        assert_eq!(shared.get("bb"), second_map.get(bb));
+        assert_eq!(shared.len(), 2);
+    }
+
+    #[test]
+    fn dropped_local_skips_its_turn() {
+        let shared = Interner::new();
+        let mut first = shared.local();
+        let second = shared.local();
+        let mut third = shared.local();
+        first.get_or_intern("aa");
+        third.get_or_intern("cc");
+        drop(second);
+        let map = std::thread::scope(|scope| {
+            let third = scope.spawn(move || third.publish());
+            first.publish();
+            third.join().unwrap()
+        });
+        assert_eq!(map.len(), 1);
+        assert_eq!(shared.get("aa").map(|symbol| symbol.to_usize()), Some(0));
+        assert_eq!(shared.get("cc").map(|symbol| symbol.to_usize()), Some(1));
+    }
+
+    #[test]
+    fn resolve_while_shared_works() {
+        let shared =
+            SharedInterner::<backend::BucketBackend<DefaultSymbol>, DefaultHashBuilder>::new();
+        let mut first = shared.local();
+        let tiger = first.get_or_intern("Tiger");
+        let tiger = first.publish().get(tiger).unwrap();
+        // Resolving does not wait for the unpublished local interner.
+        let second = shared.local();
+        let resolved = shared.resolve(tiger).unwrap();
+        let mut third = shared.local();
+        third.get_or_intern("Horse");
+        drop(second);
+        let horse = third.publish().get(expect_valid_symbol(0)).unwrap();
+        assert_eq!(resolved, "Tiger");
+        assert_eq!(shared.resolve(horse), Some("Horse"));
+        assert_eq!(shared.resolve(expect_valid_symbol(2)), None);
+    }
+}
+
+mod arena_interner {
+    use super::*;
+    use string_interner::ArenaInterner;
+
+    type Interner = ArenaInterner<DefaultSymbol, DefaultHashBuilder>;
+
+    #[test]
+    fn intern_works() {
+        let interner = Interner::new();
+        let aa = interner.intern("aa");
+        // Resolved strings can be held while new strings are interned.
+        let strings = (0..1000)
+            .map(|i| interner.intern(i.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(aa, "aa");
+        for (i, string) in strings.into_iter().enumerate() {
+            assert_eq!(string, i.to_string());
+        }
+        assert_eq!(interner.intern("aa").as_ptr(), aa.as_ptr());
+        assert_eq!(interner.len(), 1001);
+    }
+
+    #[test]
+    fn get_or_intern_works() {
+        let interner = Interner::new();
+        let aa = interner.get_or_intern("aa");
+        let bb = interner.get_or_intern_static("bb");
+        let resolved = interner.resolve(aa).unwrap();
+        assert_eq!(interner.get_or_intern("aa"), aa);
+        assert_eq!(interner.get("bb"), Some(bb));
+        assert_eq!(interner.get("cc"), None);
+        assert_eq!(interner.resolve(bb), Some("bb"));
+        assert_eq!(interner.resolve(expect_valid_symbol(1000)), None);
+        assert_eq!(resolved, "aa");
+        let interner = interner.into_inner();
+        assert_eq!(interner.resolve(aa), Some("aa"));
+        assert_eq!(interner.len(), 2);
+    }
+}
+
+mod borrowed_interner {
+    use super::*;
+    use string_interner::BorrowedInterner;
+
+    type Interner<'a> = BorrowedInterner<'a, DefaultSymbol, DefaultHashBuilder>;
+
+    /// Encodes the given strings in the layout of the `BufferBackend`.
+    fn encode_buffer(strings: &[&str]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        for string in strings {
+            assert!(string.len() < 0x80, "length must fit into a single byte");
+            buffer.push(string.len() as u8);
+            buffer.extend_from_slice(string.as_bytes());
+        }
+        buffer
+    }
+
+    #[test]
+    fn get_or_intern_works() {
+        let input = String::from("aa bb aa");
+        let mut interner = Interner::new();
+        let symbols = input
+            .split(' ')
+            .map(|string| interner.get_or_intern(string))
+            .collect::<Vec<_>>();
+        assert_eq!(symbols[0], symbols[2]);
+        assert_ne!(symbols[0], symbols[1]);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("bb"), Some(symbols[1]));
+        assert_eq!(interner.get("cc"), None);
+        assert_eq!(interner.resolve(expect_valid_symbol(2)), None);
+        let resolved = interner.resolve(symbols[1]).unwrap();
+        assert_eq!(resolved.as_ptr(), input[3..].as_ptr());
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            [(symbols[0], "aa"), (symbols[1], "bb")]
+        );
+    }
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols() {
+        let strings = (0..=usize::from(u16::MAX))
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>();
+        let mut interner = BorrowedInterner::<SymbolU16>::new();
+        let error = strings
+            .iter()
+            .map(|string| interner.try_get_or_intern(string))
+            .find_map(Result::err);
+        assert_eq!(error, Some(Error::OutOfSymbols));
+        assert_eq!(interner.len(), usize::from(u16::MAX));
+    }
+
+    #[test]
+    fn from_buffer_works() {
+        let buffer = encode_buffer(&["aa", "", "bb"]);
+        let interner = Interner::from_buffer(&buffer).unwrap();
+        assert_eq!(
+            interner
+                .iter()
+                .map(|(_, string)| string)
+                .collect::<Vec<_>>(),
+            ["aa", "", "bb"]
+        );
+        let bb = interner.get("bb").unwrap();
+        assert_eq!(bb.to_usize(), 4);
+        assert_eq!(interner.resolve(bb).unwrap().as_ptr(), buffer[5..].as_ptr());
+        assert_eq!(interner.resolve(expect_valid_symbol(3)), Some(""));
+        assert_eq!(interner.resolve(expect_valid_symbol(2)), None);
+        assert!(Interner::from_buffer(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn from_buffer_preserves_buffer_backend_symbols() {
+        let strings = ["Tiger", "", "Horse", &"x".repeat(200), "Zebra"];
+        let mut original =
+            string_interner::StringInterner::<backend::BufferBackend<DefaultSymbol>>::new();
+        let symbols = strings.map(|string| original.get_or_intern(string));
+        let serialized = serde_json::to_value(original.backend()).unwrap();
+        let (_, buffer): (usize, Vec<u8>) = serde_json::from_value(serialized).unwrap();
+        let mut interner = Interner::from_buffer(&buffer).unwrap();
+        for (symbol, string) in symbols.into_iter().zip(strings) {
+            assert_eq!(interner.resolve(symbol), Some(string));
+            assert_eq!(interner.get(string), Some(symbol));
+        }
+        assert!(Iterator::eq(
+            interner.iter(),
+            symbols.into_iter().zip(strings)
+        ));
+        // Strings interned afterwards get the symbols of the original interner.
+        assert_eq!(interner.get_or_intern("Horse"), symbols[2]);
+        assert_eq!(interner.get_or_intern("Cat"), original.get_or_intern("Cat"));
+        assert_eq!(interner.get_or_intern("Dog"), original.get_or_intern("Dog"));
+        assert_eq!(interner.resolve(original.get("Dog").unwrap()), Some("Dog"));
+    }
+
+    #[test]
+    fn from_buffer_rejects_invalid_buffers() {
+        let from_buffer = |buffer: &[u8]| Interner::from_buffer(buffer).map(|_| ()).unwrap_err();
+        assert_eq!(
+            from_buffer(&encode_buffer(&["aa", "bb", "aa"])),
+            Error::DuplicateString
+        );
+        // Exceeding string length.
+        assert_eq!(from_buffer(&[3, b'a', b'a']), Error::InvalidBuffer);
+        // Incomplete length prefix.
+        assert_eq!(from_buffer(&[0x80]), Error::InvalidBuffer);
+        // Invalid UTF-8.
+        assert_eq!(from_buffer(&[1, 0xFF]), Error::InvalidBuffer);
+    }
+}
+
+mod persist {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn read_from_rejects_other_backends_and_symbols() {
+        let interner = ["aa", "bb"]
+            .iter()
+            .collect::<string_interner::StringInterner<backend::StringBackend<DefaultSymbol>>>();
+        let mut snapshot = Vec::new();
+        interner.write_to(&mut snapshot).unwrap();
+        let error =
+            string_interner::StringInterner::<backend::BufferBackend<DefaultSymbol>>::read_from(
+                &snapshot[..],
+            )
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error =
+            string_interner::StringInterner::<backend::StringBackend<SymbolU16>>::read_from(
+                &snapshot[..],
+            )
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_from_restores_removed_symbols() {
+        type Interner = string_interner::StringInterner<backend::FreeListBackend<DefaultSymbol>>;
+        let mut interner = ["aa", "", "bb", "cc", "dd"].iter().collect::<Interner>();
+        for string in ["aa", "bb", "cc"] {
+            let symbol = interner.get(string).unwrap();
+            assert!(interner.remove(symbol));
+        }
+        let mut snapshot = Vec::new();
+        interner.write_to(&mut snapshot).unwrap();
+        let mut restored = Interner::read_from(&snapshot[..]).unwrap();
+        assert!(Iterator::eq(restored.iter(), interner.iter()));
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.get(""), interner.get(""));
+        assert_eq!(restored.get("aa"), None);
+        for index in [0, 2, 3] {
+            assert_eq!(restored.resolve(expect_valid_symbol(index)), None);
+        }
+        // The symbols of the removed strings are reused.
+        let ee = restored.get_or_intern("ee");
+        assert!([0, 2, 3].contains(&ee.to_usize()));
+    }
+
+    #[test]
+    fn read_from_stops_after_snapshot() {
+        let interner = ["aa", "bb"]
+            .iter()
+            .collect::<string_interner::DefaultStringInterner>();
+        let mut snapshot = Vec::new();
+        interner.write_to(&mut snapshot).unwrap();
+        interner.write_to(&mut snapshot).unwrap();
+        let mut reader = &snapshot[..];
+        let first = string_interner::DefaultStringInterner::read_from(&mut reader).unwrap();
+        let second = string_interner::DefaultStringInterner::read_from(&mut reader).unwrap();
+        assert!(reader.is_empty());
+        assert_eq!(first, interner);
+        assert_eq!(second, interner);
+    }
+}
+
+#[cfg(feature = "mmap")]
+mod mmap_backend {
+    use super::*;
+    use backend::MmapBackend;
+    use std::path::PathBuf;
+
+    type Interner = string_interner::StringInterner<MmapBackend<DefaultSymbol>, DefaultHashBuilder>;
+
+    /// Writes the `interner` to a temporary file and returns its path.
+    fn write_temp_file<B>(name: &str, interner: &string_interner::StringInterner<B>) -> PathBuf
+    where
+        B: Backend,
+    {
+        let path =
+            std::env::temp_dir().join(format!("string-interner-{}-{name}.bin", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        interner.write_to(std::io::BufWriter::new(file)).unwrap();
+        path
+    }
+
+    /// Memory maps the file at `path` and removes the file afterwards.
+    fn open(path: PathBuf) -> std::io::Result<MmapBackend<DefaultSymbol>> {
+        // SAFETY: The file is not modified while it is mapped.
+        let backend = unsafe { MmapBackend::open(&path) };
+        std::fs::remove_file(path).unwrap();
+        backend
+    }
+
+    fn assert_open_works<B>(name: &str, interner: &string_interner::StringInterner<B>)
+    where
+        B: Backend<Symbol = DefaultSymbol>,
+    {
+        let backend = open(write_temp_file(name, interner)).unwrap();
+        assert!(Iterator::eq(backend.iter(), interner.iter()));
+        let mut mapped = Interner::from_backend(backend).unwrap();
+        assert_eq!(mapped.len(), interner.len());
+        for (symbol, string) in interner {
+            assert_eq!(mapped.resolve(symbol), Some(string));
+            assert_eq!(mapped.get(string), Some(symbol));
+        }
+        assert_eq!(mapped.get("zz"), None);
+        assert_eq!(mapped.try_get_or_intern("zz"), Err(Error::ReadOnly));
+        // Already interned strings can still be looked up via `get_or_intern`.
+        if let Some((symbol, string)) = interner.iter().next() {
+            assert_eq!(mapped.try_get_or_intern(string), Ok(symbol));
+        }
+    }
+
+    #[test]
+    fn open_works() {
+        let strings = ["aa", "", "bb", "ä€", "cc"];
+        assert_open_works(
+            "string",
+            &strings
+                .iter()
+                .collect::<string_interner::StringInterner<backend::StringBackend>>(),
+        );
+        assert_open_works(
+            "buffer",
+            &strings
+                .iter()
+                .collect::<string_interner::StringInterner<backend::BufferBackend>>(),
+        );
+        let mut interner = strings
+            .iter()
+            .collect::<string_interner::StringInterner<backend::FreeListBackend>>();
+        let bb = interner.get("bb").unwrap();
+        interner.remove(bb);
+        assert_open_works("free-list", &interner);
+        assert_open_works("empty", &<string_interner::DefaultStringInterner>::new());
+    }
+
+    #[test]
+    fn open_works_across_blocks() {
+        let strings = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>();
+        assert_open_works(
+            "blocks",
+            &strings
+                .iter()
+                .collect::<string_interner::StringInterner<backend::StringBackend>>(),
+        );
+        let mut interner = strings
+            .iter()
+            .collect::<string_interner::StringInterner<backend::FreeListBackend>>();
+        for string in ["0", "300", "555", "999"] {
+            let symbol = interner.get(string).unwrap();
+            interner.remove(symbol);
+        }
+        assert_open_works("blocks-free-list", &interner);
+    }
+
+    #[test]
+    #[should_panic(expected = "the interner backend is read-only")]
+    fn intern_panics() {
+        MmapBackend::<DefaultSymbol>::default().intern("aa");
+    }
+
+    #[test]
+    fn open_rejects_invalid_files() {
+        let interner = ["aa", "bb"]
+            .iter()
+            .collect::<string_interner::DefaultStringInterner>();
+        let path = write_temp_file("corrupted", &interner);
+        let mut bytes = std::fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+        std::fs::write(&path, &bytes).unwrap();
+        let error = open(path).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        let path = write_temp_file("truncated", &interner);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(open(path).is_err());
+    }
+}
+
+mod bytes_interner {
+    use super::*;
+    use string_interner::BytesInterner;
+
+    type Interner = BytesInterner<DefaultSymbol, DefaultHashBuilder>;
+
+    #[test]
+    fn get_or_intern_works() {
+        let mut interner = Interner::new();
+        let invalid = interner.get_or_intern(b"\xFF\xFE");
+        let empty = interner.get_or_intern([]);
+        let aa = interner.get_or_intern("aa");
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.get_or_intern(vec![0xFF, 0xFE]), invalid);
+        assert_eq!(interner.get_or_intern(b""), empty);
+        assert_eq!(interner.get(b"aa"), Some(aa));
+        assert_eq!(interner.get(b"bb"), None);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.resolve(invalid), Some(&b"\xFF\xFE"[..]));
+        assert_eq!(interner.resolve(empty), Some(&b""[..]));
+        assert_eq!(interner.resolve(aa), Some(&b"aa"[..]));
+        // SAFETY: The symbol has been returned by the interner.
+        assert_eq!(unsafe { interner.resolve_unchecked(aa) }, b"aa");
+        assert_eq!(interner.resolve(expect_valid_symbol(1000)), None);
+    }
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols() {
+        let mut interner = BytesInterner::<SymbolU16>::new();
+        let error = (0_u32..)
+            .map(|i| interner.try_get_or_intern(i.to_le_bytes()))
+            .find_map(Result::err);
+        assert_eq!(error, Some(Error::OutOfSymbols));
+        let len = interner.len();
+        assert!(interner.try_get_or_intern(b"too many").is_err());
+        assert_eq!(interner.len(), len);
+        assert!(Iterator::eq(
+            interner.iter().map(|(_, bytes)| bytes),
+            (0_u32..len as u32)
+                .map(u32::to_le_bytes)
+                .collect::<Vec<_>>()
+                .iter()
+                .map(|bytes| &bytes[..])
+        ));
+    }
+
+    #[test]
+    fn iter_works() {
+        let strings: [&[u8]; 4] = [b"aa", b"\x80", b"", b"bb"];
+        let interner = strings.iter().collect::<Interner>();
+        assert_eq!(interner.iter().len(), strings.len());
+        for ((symbol, bytes), expected) in interner.iter().zip(strings) {
+            assert_eq!(bytes, expected);
+            assert_eq!(interner.get(bytes), Some(symbol));
+        }
+        assert_eq!(interner.clone(), interner);
+    }
+}
+
+mod path_interner {
+    use super::*;
+    use std::path::{Path, PathBuf};
+    use string_interner::PathInterner;
+
+    type Interner = PathInterner<DefaultSymbol, DefaultHashBuilder>;
+
+    #[test]
+    fn get_or_intern_works() {
+        let mut interner = Interner::new();
+        let main = interner.get_or_intern("src/main.rs");
+        let lib = interner.get_or_intern(PathBuf::from("src/lib.rs"));
+        assert_eq!(interner.get_or_intern(Path::new("src/main.rs")), main);
+        assert_eq!(interner.get(std::ffi::OsStr::new("src/lib.rs")), Some(lib));
+        assert_eq!(interner.get("src/bin.rs"), None);
+        // Paths are deduplicated by their encoded bytes.
+        let redundant = interner.get_or_intern("src//main.rs");
+        assert_ne!(redundant, main);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.resolve(main), Some(Path::new("src/main.rs")));
+        assert_eq!(
+            interner.resolve(redundant).map(Path::as_os_str),
+            Some(std::ffi::OsStr::new("src//main.rs"))
+        );
+        assert_eq!(interner.resolve(expect_valid_symbol(1000)), None);
+    }
+
+    #[test]
+    #[cfg(not(unix))]
+    fn resolve_rejects_symbols_within_paths() {
+        let mut interner = Interner::new();
+        // The length prefix of the path is followed by `\u{1}` which decodes as
+        // the length prefix of the first half of `é` for the symbol below.
+        let symbol = interner.get_or_intern("\u{1}é");
+        let within = expect_valid_symbol(symbol.to_usize() + 1);
+        assert_eq!(interner.resolve(within), None);
+        assert_eq!(interner.resolve(symbol), Some(Path::new("\u{1}é")));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn non_utf8_paths_work() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+        let path = Path::new(OsStr::from_bytes(b"src/\xFF\xFE.rs"));
+        let lossy = PathBuf::from(path.to_string_lossy().into_owned());
+        let mut interner = Interner::new();
+        let symbol = interner.get_or_intern(path);
+        assert_ne!(interner.get_or_intern(&lossy), symbol);
+        assert_eq!(interner.resolve(symbol), Some(path));
+        assert_eq!(
+            interner.resolve(symbol).unwrap().as_os_str().as_bytes(),
+            b"src/\xFF\xFE.rs"
+        );
+    }
+
+    #[test]
+    fn iter_works() {
+        let paths = ["a", "a/b", "", "a/b/c"];
+        let interner = paths.iter().collect::<Interner>();
+        assert_eq!(interner.iter().len(), paths.len());
+        for ((symbol, path), expected) in interner.iter().zip(paths) {
+            assert_eq!(path, Path::new(expected));
+            assert_eq!(interner.get(path), Some(symbol));
+        }
+        assert_eq!(interner.clone(), interner);
+    }
+}
+
+mod generic_interner {
+    use super::*;
+    use string_interner::generic::{
+        BucketSliceBackend,
+        GenericBackend,
+        Interner,
+        OwnedBackend,
+        SliceBackend,
+    };
+
+    /// Asserts that an interner with the backend `B` interns and resolves `[u32]` slices.
+    fn assert_slices_work<B>()
+    where
+        B: GenericBackend<[u32], Symbol = DefaultSymbol> + Clone + PartialEq + core::fmt::Debug,
+    {
+        let mut interner = Interner::<[u32], B>::new();
+        let empty = interner.get_or_intern(&[]);
+        let signature = interner.get_or_intern(&[1, 2, 3]);
+        let prefix = interner.get_or_intern(&[1, 2]);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.get_or_intern(&[1, 2, 3]), signature);
+        assert_eq!(interner.get(&[]), Some(empty));
+        assert_eq!(interner.get(&[1]), None);
+        assert_eq!(interner.resolve(empty), Some(&[][..]));
+        assert_eq!(interner.resolve(signature), Some(&[1, 2, 3][..]));
+        assert_eq!(interner.resolve(prefix), Some(&[1, 2][..]));
+        // SAFETY: The symbol has been returned by the interner.
+        assert_eq!(unsafe { interner.resolve_unchecked(prefix) }, &[1, 2]);
+        assert_eq!(interner.resolve(expect_valid_symbol(1000)), None);
+        // Interned slices must remain resolvable while the backend grows.
+        let slices = (0..1000_u32)
+            .map(|i| vec![i; i as usize % 7])
+            .collect::<Vec<_>>();
+        interner.extend(slices.iter().map(Vec::as_slice));
+        assert_eq!(interner.resolve(signature), Some(&[1, 2, 3][..]));
+        let clone = interner.clone();
+        assert_eq!(clone, interner);
+        assert_eq!(interner.iter().count(), interner.len());
+        for (symbol, slice) in &clone {
+            assert_eq!(interner.get(slice), Some(symbol));
+            assert_eq!(interner.resolve(symbol), Some(slice));
+        }
+    }
+
+    #[test]
+    fn slice_backend_works() {
+        assert_slices_work::<SliceBackend<u32>>();
+    }
+
+    #[test]
+    fn bucket_slice_backend_works() {
+        assert_slices_work::<BucketSliceBackend<u32>>();
+    }
+
+    #[test]
+    fn owned_backend_works() {
+        assert_slices_work::<OwnedBackend<[u32]>>();
+    }
+
+    #[test]
+    fn owned_backend_interns_sized_values() {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+        let points = [(0, 0), (1, 2), (0, 0), (2, 1)].map(|(x, y)| Point { x, y });
+        let interner = points
+            .iter()
+            .collect::<Interner<Point, OwnedBackend<Point>>>();
+        assert_eq!(interner.len(), 3);
+        for point in &points {
+            let symbol = interner.get(point).unwrap();
+            assert_eq!(interner.resolve(symbol), Some(point));
+        }
+    }
+
+    #[test]
+    fn try_get_or_intern_out_of_symbols() {
+        let mut interner = Interner::<[u32], SliceBackend<u32, SymbolU16>>::new();
+        let error = (0_u32..)
+            .map(|i| interner.try_get_or_intern(&[i]))
+            .find_map(Result::err);
+        assert_eq!(error, Some(Error::OutOfSymbols));
+        let len = interner.len();
+        assert!(interner.try_get_or_intern(&[u32::MAX, 0]).is_err());
+        assert_eq!(interner.len(), len);
+        assert_eq!(interner.iter().count(), len);
+    }
+
+    #[test]
+    fn str_specialization_works() {
+        let strings = ["aa", "bb", "", "aa", "cc"];
+        let generic = strings
+            .iter()
+            .copied()
+            .collect::<Interner<str, backend::StringBackend>>();
+        let interner = strings
+            .iter()
+            .collect::<string_interner::StringInterner<backend::StringBackend>>();
+        assert_eq!(generic.len(), interner.len());
+        assert!(Iterator::eq(generic.iter(), interner.iter()));
+        for string in strings {
+            assert_eq!(generic.get(string), interner.get(string));
+        }
+    }
+}
+
+mod normalized_interner {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use string_interner::normalize::{AsciiCaseInsensitive, Normalizer};
+
+    type Interner<B = backend::StringBackend<DefaultSymbol>> =
+        string_interner::StringInterner<B, DefaultHashBuilder, AsciiCaseInsensitive>;
+
+    #[test]
+    fn ascii_case_insensitive_works() {
+        let mut interner = <Interner>::new();
+        let foo = interner.get_or_intern("Foo");
+        let bar = interner.get_or_intern("bar");
+        assert_eq!(interner.get_or_intern("FOO"), foo);
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("fOO"), Some(foo));
+        assert_eq!(interner.get("BAR"), Some(bar));
+        assert_eq!(interner.get("fo"), None);
+        assert_eq!(interner.len(), 2);
+        // The first-seen spelling is preserved.
+        assert_eq!(interner.resolve(foo), Some("Foo"));
+        assert_eq!(interner.resolve(bar), Some("bar"));
+        assert!(!interner.get_or_intern_with_status("BAR").1);
+        assert_eq!(interner.entry("bAr").symbol(), Some(bar));
+        let baz = interner.entry("Baz").or_intern();
+        assert_eq!(interner.get("BAZ"), Some(baz));
+        assert_eq!(interner.get_or_intern_static("baz"), baz);
+    }
+
+    #[test]
+    fn non_ascii_is_compared_exactly() {
+        let mut interner = <Interner>::new();
+        let lower = interner.get_or_intern("straße");
+        assert_eq!(interner.get_or_intern("STRAßE"), lower);
+        assert_ne!(interner.get_or_intern("STRASSE"), lower);
+        assert_ne!(interner.get_or_intern("ä"), interner.get_or_intern("Ä"));
+    }
+
+    #[test]
+    fn long_strings_work() {
+        let mut interner = <Interner>::new();
+        let long = "Some Identifier That Is Longer Than The Chunk Size Of The Hasher";
+        let symbol = interner.get_or_intern(long);
+        assert_eq!(interner.get(long.to_ascii_uppercase()), Some(symbol));
+        assert_eq!(interner.get(long.to_ascii_lowercase()), Some(symbol));
+        assert_eq!(interner.get(&long[1..]), None);
+    }
+
+    #[test]
+    fn rebuilding_the_interner_works() {
+        let mut interner = <Interner>::new();
+        let foo = interner.get_or_intern("Foo");
+        let checkpoint = interner.checkpoint();
+        let bar = interner.get_or_intern("Bar");
+        interner.reserve(1000);
+        assert_eq!(interner.get("BAR"), Some(bar));
+        interner.rollback_to(checkpoint);
+        assert_eq!(interner.get("bar"), None);
+        let frozen = interner.clone().freeze_compact();
+        assert_eq!(frozen.get("FOO"), Some(foo));
+        let map = interner.compact();
+        assert_eq!(interner.get("fOo"), map.get(foo));
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut interner = Interner::<backend::FreeListBackend<DefaultSymbol>>::new();
+        let foo = interner.get_or_intern("Foo");
+        assert!(interner.remove(foo));
+        assert_eq!(interner.get("FOO"), None);
+        let foo = interner.get_or_intern("FOO");
+        assert_eq!(interner.resolve(foo), Some("FOO"));
+    }
+
+    #[test]
+    fn from_backend_rejects_equivalent_strings() {
+        let mut backend = backend::StringBackend::<DefaultSymbol>::default();
+        backend.intern("Foo");
+        assert!(<Interner>::from_backend(backend.clone()).is_ok());
+        backend.intern("FOO");
+        assert_eq!(
+            <Interner>::from_backend(backend).err(),
+            Some(Error::DuplicateString)
+        );
+    }
+
+    #[test]
+    fn persist_works() {
+        let interner = ["Foo", "bar"].iter().collect::<Interner>();
+        let mut snapshot = Vec::new();
+        interner.write_to(&mut snapshot).unwrap();
+        let restored = <Interner>::read_from(&snapshot[..]).unwrap();
+        assert_eq!(restored, interner);
+        assert_eq!(restored.get("FOO"), interner.get("Foo"));
+        // Strings that are distinct without normalization cannot be restored.
+        let interner = ["Foo", "FOO"]
+            .iter()
+            .collect::<string_interner::StringInterner<backend::StringBackend<DefaultSymbol>>>();
+        let mut snapshot = Vec::new();
+        interner.write_to(&mut snapshot).unwrap();
+        assert!(<Interner>::read_from(&snapshot[..]).is_err());
+    }
+
+    #[test]
+    fn serde_with_symbols_works() {
+        use string_interner::WithSymbols;
+        let interner = ["Foo", "bar"].iter().collect::<Interner>();
+        let json = serde_json::to_string(&WithSymbols(&interner)).unwrap();
+        let restored = serde_json::from_str::<WithSymbols<Interner>>(&json)
+            .unwrap()
+            .into_inner();
+        assert_eq!(restored, interner);
+        assert_eq!(restored.get("BAR"), interner.get("bar"));
+        let json = r#"[[0,"Foo"],[1,"FOO"]]"#;
+        assert!(serde_json::from_str::<WithSymbols<Interner>>(json).is_err());
+    }
+
+    #[test]
+    fn resolve_cstr_works() {
+        let mut interner = Interner::<backend::CStrBackend<DefaultSymbol>>::new();
+        let main = interner.get_or_intern("Main");
+        assert_eq!(interner.get_or_intern("MAIN"), main);
+        assert_eq!(interner.resolve_cstr(main), Some(c"Main"));
+    }
+
+    #[test]
+    fn custom_normalizer_works() {
+        /// Treats strings as equal if they are equal after trimming whitespace.
+        enum Trim {}
+
+        impl Normalizer for Trim {
+            fn hash<H>(string: &str, state: &mut H)
+            where
+                H: Hasher,
+            {
+                string.trim().hash(state)
+            }
+
+            fn eq(lhs: &str, rhs: &str) -> bool {
+                lhs.trim() == rhs.trim()
+            }
+        }
+
+        let mut interner = string_interner::StringInterner::<
+            backend::BufferBackend,
+            DefaultHashBuilder,
+            Trim,
+        >::new();
+        let name = interner.get_or_intern(" name ");
+        assert_eq!(interner.get_or_intern("name"), name);
+        assert_eq!(interner.get("name\n"), Some(name));
+        assert_eq!(interner.resolve(name), Some(" name "));
+        assert_eq!(interner.len(), 1);
+    }
 }